@@ -0,0 +1,97 @@
+//! Compares allocations of the owned and borrowed anime types while
+//! deserializing a page of search results.
+//!
+//! Run with `cargo bench --bench borrowed`.
+
+use std::{
+    alloc::{
+        GlobalAlloc,
+        Layout,
+        System,
+    },
+    hint::black_box,
+    sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    },
+    time::Instant,
+};
+
+use jikan_rs::types::{
+    Root,
+    anime::AnimeSearchResults,
+    borrowed,
+};
+use serde::Deserialize;
+use serde_json::Value;
+
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(new_size, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+const ITEMS: usize = 25;
+const ITERATIONS: usize = 1000;
+
+/// A page of search results, built from the anime fixture.
+fn search_page() -> Vec<u8> {
+    let mut root: Value =
+        serde_json::from_str(include_str!("../tests/fixtures/anime/anime.json")).unwrap();
+    let anime = root["data"].take();
+    root["data"] = Value::Array(vec![anime; ITEMS]);
+    serde_json::to_vec(&root).unwrap()
+}
+
+fn run<'a, T>(name: &str, body: &'a [u8])
+where
+    T: Deserialize<'a>,
+{
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        let root: Root<T> = serde_json::from_slice(black_box(body)).unwrap();
+        black_box(root);
+    }
+    let elapsed = start.elapsed();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations;
+    let bytes = BYTES.load(Ordering::Relaxed) - bytes;
+
+    println!(
+        "{name:>8}: {:>6} allocations, {:>8} bytes, {:>8.1?} per page",
+        allocations / ITERATIONS,
+        bytes / ITERATIONS,
+        elapsed / ITERATIONS as u32,
+    );
+}
+
+fn main() {
+    let body = search_page();
+    println!(
+        "{ITEMS} anime per page, {} bytes, {ITERATIONS} iterations",
+        body.len()
+    );
+    run::<AnimeSearchResults>("owned", &body);
+    run::<borrowed::AnimeSearchResults>("borrowed", &body);
+}
@@ -3,11 +3,17 @@
 /// `/anime` endpoints.
 pub mod anime;
 mod client;
+mod cursor;
+mod decode;
+pub mod download;
 mod endpoint;
 mod error;
+pub mod franchise;
+pub mod mock;
 mod page;
 mod query;
 mod query_params;
+mod raw;
 mod utils;
 
 pub use client::{
@@ -15,14 +21,34 @@ pub use client::{
     Client,
     RestClient,
 };
-pub use error::ApiError;
+pub use cursor::PageCursor;
+pub(crate) use decode::WarningHandler;
+pub use decode::{
+    DecodeMode,
+    DecodeWarning,
+    DecodeWarningKind,
+};
+pub use error::{
+    ApiError,
+    BodyError,
+    ResponseError,
+    UnknownField,
+};
 pub use page::{
     AsyncIterator,
+    ConcurrentPagedIter,
+    ConcurrentPages,
+    Page,
     PagedEndpointExt,
+    PagedIter,
+    Pages,
 };
 pub use query::{
     AsyncQuery,
+    AsyncRawQuery,
     Query,
+    RawQuery,
 };
+pub use raw::RawResponse;
 
 pub use crate::types::Root;
@@ -2,16 +2,22 @@ use std::borrow::Cow;
 
 use http::Method;
 
-use crate::api::endpoint::Endpoint;
+use crate::{
+    api::endpoint::Endpoint,
+    types::{
+        AnimeId,
+        EpisodeNumber,
+    },
+};
 
 /// Retrieves an anime episode resource.
 #[derive(Debug, Clone, Builder)]
 #[builder(setter(into))]
 pub struct Episode {
     #[doc = r"`ID` of the anime"]
-    id: u32,
-    #[doc = r"`ID` of the episode"]
-    episode: u32,
+    id: AnimeId,
+    #[doc = r"Number of the episode"]
+    episode: EpisodeNumber,
 }
 
 impl Episode {
@@ -2,9 +2,12 @@ use std::borrow::Cow;
 
 use http::Method;
 
-use crate::api::{
-    endpoint::Endpoint,
-    page::Pageable,
+use crate::{
+    api::{
+        endpoint::Endpoint,
+        page::Pageable,
+    },
+    types::AnimeId,
 };
 
 /// Retrieves a list of anime episodes.
@@ -12,7 +15,7 @@ use crate::api::{
 #[builder(setter(into))]
 pub struct Episodes {
     #[doc = r"`ID` of the anime"]
-    id: u32,
+    id: AnimeId,
 }
 
 impl Episodes {
@@ -33,3 +36,30 @@ impl Endpoint for Episodes {
 }
 
 impl Pageable for Episodes {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        JikanApiClient,
+        api::{
+            page::InnerState,
+            utils,
+        },
+    };
+
+    #[test]
+    fn paged_url() {
+        let endpoint = Episodes::builder().id(1u32).build().unwrap();
+        let state = InnerState {
+            endpoint: &endpoint,
+            page: 3,
+            limit: None,
+        };
+        let (req, _) = utils::build_paged_request(&state, &JikanApiClient::new()).unwrap();
+        assert_eq!(
+            req.uri_ref().unwrap(),
+            "https://api.jikan.moe/v4/anime/1/episodes?page=3"
+        );
+    }
+}
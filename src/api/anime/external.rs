@@ -2,14 +2,17 @@ use std::borrow::Cow;
 
 use http::Method;
 
-use crate::api::endpoint::Endpoint;
+use crate::{
+    api::endpoint::Endpoint,
+    types::AnimeId,
+};
 
 /// Retrieves anime external links.
 #[derive(Debug, Clone, Builder)]
 #[builder(setter(into))]
 pub struct External {
     #[doc = r"`ID` of the anime"]
-    id: u32,
+    id: AnimeId,
 }
 
 impl External {
@@ -3,18 +3,24 @@ use std::borrow::Cow;
 use http::Method;
 use serde::Serialize;
 
-use crate::api::{
-    Query,
-    endpoint::Endpoint,
-    error::BodyError,
-    query_params::QueryParams,
+use crate::{
+    api::{
+        endpoint::Endpoint,
+        error::BodyError,
+        query_params::QueryParams,
+    },
+    types::AnimeId,
 };
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+/// Enum for the 'filter' query parameter of the forum endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
 #[serde(rename_all = "snake_case")]
-enum ForumFilter {
+pub enum ForumFilter {
+    /// All forum topics.
     All,
+    /// Episode discussion topics.
     Episode,
+    /// Other topics.
     Other,
 }
 
@@ -24,7 +30,7 @@ enum ForumFilter {
 pub struct Forum {
     #[doc = r"`ID` of the anime"]
     #[serde(skip)]
-    id: u32,
+    id: AnimeId,
     #[doc = r"Filter forum topics"]
     #[builder(default, setter(strip_option))]
     filter: Option<ForumFilter>,
@@ -50,3 +56,39 @@ impl Endpoint for Forum {
         QueryParams::with(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        JikanApiClient,
+        api::utils,
+    };
+
+    fn url(endpoint: &Forum) -> String {
+        let (req, _) = utils::build_request(endpoint, &JikanApiClient::new()).unwrap();
+        req.uri_ref().unwrap().to_string()
+    }
+
+    #[test]
+    fn without_filter() {
+        let endpoint = Forum::builder().id(1u32).build().unwrap();
+        assert_eq!(url(&endpoint), "https://api.jikan.moe/v4/anime/1/forum");
+    }
+
+    #[test]
+    fn with_filter() {
+        let filters = [
+            (ForumFilter::All, "all"),
+            (ForumFilter::Episode, "episode"),
+            (ForumFilter::Other, "other"),
+        ];
+        for (filter, expected) in filters {
+            let endpoint = Forum::builder().id(1u32).filter(filter).build().unwrap();
+            assert_eq!(
+                url(&endpoint),
+                format!("https://api.jikan.moe/v4/anime/1/forum?filter={expected}")
+            );
+        }
+    }
+}
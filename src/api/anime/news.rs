@@ -2,9 +2,12 @@ use std::borrow::Cow;
 
 use http::Method;
 
-use crate::api::{
-    endpoint::Endpoint,
-    page::Pageable,
+use crate::{
+    api::{
+        endpoint::Endpoint,
+        page::Pageable,
+    },
+    types::AnimeId,
 };
 
 /// Retrieves a list of news articles related to the entry.
@@ -12,7 +15,7 @@ use crate::api::{
 #[builder(setter(into))]
 pub struct News {
     #[doc = r"`ID` of the anime"]
-    id: u32,
+    id: AnimeId,
 }
 
 impl News {
@@ -3,11 +3,14 @@ use std::borrow::Cow;
 use http::Method;
 use serde::Serialize;
 
-use crate::api::{
-    endpoint::Endpoint,
-    error::BodyError,
-    page::Pageable,
-    query_params::QueryParams,
+use crate::{
+    api::{
+        endpoint::Endpoint,
+        error::BodyError,
+        page::Pageable,
+        query_params::QueryParams,
+    },
+    types::AnimeId,
 };
 
 /// Retrieves anime reviews.
@@ -16,7 +19,7 @@ use crate::api::{
 pub struct Reviews {
     #[doc = r"`ID` of the anime"]
     #[serde(skip)]
-    id: u32,
+    id: AnimeId,
     #[doc = r"Any reviews left during an ongoing anime"]
     #[builder(default, setter(strip_option))]
     preliminary: Option<bool>,
@@ -47,3 +50,48 @@ impl Endpoint for Reviews {
 }
 
 impl Pageable for Reviews {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        JikanApiClient,
+        api::{
+            page::InnerState,
+            utils,
+        },
+    };
+
+    fn url(endpoint: &Reviews, page: u32) -> String {
+        let state = InnerState {
+            endpoint,
+            page,
+            limit: None,
+        };
+        let (req, _) = utils::build_paged_request(&state, &JikanApiClient::new()).unwrap();
+        req.uri_ref().unwrap().to_string()
+    }
+
+    #[test]
+    fn without_filters() {
+        let endpoint = Reviews::builder().id(1u32).build().unwrap();
+        assert_eq!(
+            url(&endpoint, 1),
+            "https://api.jikan.moe/v4/anime/1/reviews?page=1"
+        );
+    }
+
+    #[test]
+    fn with_filters() {
+        let endpoint = Reviews::builder()
+            .id(1u32)
+            .preliminary(true)
+            .spoilers(false)
+            .build()
+            .unwrap();
+        assert_eq!(
+            url(&endpoint, 2),
+            "https://api.jikan.moe/v4/anime/1/reviews?preliminary=true&spoilers=false&page=2"
+        );
+    }
+}
@@ -1,13 +1,26 @@
 use std::borrow::Cow;
 
+use derive_builder::UninitializedFieldError;
 use http::Method;
 use serde::Serialize;
+use thiserror::Error;
 
-use crate::api::{
-    endpoint::Endpoint,
-    error::BodyError,
-    page::Pageable,
-    query_params::QueryParams,
+use crate::{
+    api::{
+        endpoint::Endpoint,
+        error::BodyError,
+        page::Pageable,
+        query_params::{
+            QueryParams,
+            comma_separated,
+        },
+    },
+    types::{
+        DateError,
+        GenreId,
+        PartialDate,
+        ProducerId,
+    },
 };
 
 /// Enum for the 'type' query parameter.
@@ -42,7 +55,9 @@ pub enum AnimeStatus {
 #[serde(rename_all = "snake_case")]
 pub enum AnimeRating {
     G,
+    #[serde(rename = "pg")]
     PG,
+    #[serde(rename = "pg13")]
     PG13,
     R17,
     R,
@@ -76,52 +91,187 @@ pub enum AnimeSorting {
     Desc,
 }
 
+/// Maximum number of results per page allowed by Jikan.
+const MAX_LIMIT: u32 = 25;
+
 /// Search and retrieve anime resource data.
 #[derive(Debug, Clone, Builder, Serialize)]
-#[builder(setter(into))]
+#[builder(
+    setter(into),
+    build_fn(validate = "Self::validate", error = "AnimeSearchBuilderError")
+)]
 pub struct AnimeSearch<'a> {
-    // unapproved: bool,
-    /// The number of maximum results to return.
-    limit: u32,
+    /// Include entries which are not approved on MyAnimeList yet.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    #[builder(default)]
+    unapproved: bool,
+    /// Page of results to return.
+    ///
+    /// Ignored when iterating over the results with
+    /// [`PagedEndpointExt::iter`](crate::api::PagedEndpointExt::iter), which
+    /// sets the page itself.
+    #[builder(default, setter(strip_option))]
+    page: Option<u32>,
+    /// The number of maximum results to return (up to 25).
+    #[builder(default, setter(strip_option))]
+    limit: Option<u32>,
     /// Query string.
     #[serde(rename = "q")]
+    #[builder(default, setter(strip_option))]
     query: Option<Cow<'a, str>>,
     /// The type of anime to search.
     #[serde(rename = "type")]
+    #[builder(default, setter(strip_option))]
     query_type: Option<AnimeType>,
     /// Score of anime to search.
+    #[builder(default, setter(strip_option))]
     score: Option<f32>,
     /// The minimum score to search.
+    #[builder(default, setter(strip_option))]
     min_score: Option<f32>,
     /// The maximum score to search.
+    #[builder(default, setter(strip_option))]
     max_score: Option<f32>,
     /// Status of anime to search.
+    #[builder(default, setter(strip_option))]
     status: Option<AnimeStatus>,
     /// Audience rating of anime.
+    #[builder(default, setter(strip_option))]
     rating: Option<AnimeRating>,
     /// Filter adult entries.
-    sfw: Option<bool>,
-    // TODO: custom type instead of ids
-    #[doc = r"Filter by genre(s) `ID`s. Can pass multiple with a comma as a delimiter"]
-    genres: Option<Cow<'a, str>>,
-    // TODO: custom type instead of ids
-    #[doc = r"Filter by genre(s) `ID`s. Can pass multiple with a comma as a delimiter"]
-    genres_exclude: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    #[builder(default)]
+    sfw: bool,
+    /// Filter by genres, themes or demographics.
+    #[serde(serialize_with = "comma_separated")]
+    #[builder(default, setter(strip_option))]
+    genres: Option<Vec<GenreId>>,
+    /// Exclude genres, themes or demographics.
+    #[serde(serialize_with = "comma_separated")]
+    #[builder(default, setter(strip_option))]
+    genres_exclude: Option<Vec<GenreId>>,
     /// Sorting result by this parameter.
+    #[builder(default, setter(strip_option))]
     order_by: Option<AnimeOrderBy>,
     /// Sorting order.
+    #[serde(rename = "sort")]
+    #[builder(default, setter(strip_option))]
     sorting: Option<AnimeSorting>,
     /// Search entries starting with the given letter.
+    #[builder(default, setter(strip_option))]
     letter: Option<Cow<'a, str>>,
-    // TODO: custom type instead of ids
-    #[doc = r"Filter by producer(s) `ID`s. Can pass multiple with a comma as a delimiter"]
-    producers: Option<Cow<'a, str>>,
-    // TODO: custom date serializer
-    #[doc = r"Filter by starting date. Format: `YYYY-MM-DD`."]
-    start_date: Option<Cow<'a, str>>,
-    // TODO: custom date serializer
-    #[doc = r"Filter by ending date. Format: `YYYY-MM-DD`."]
-    end_date: Option<Cow<'a, str>>,
+    /// Filter by producers.
+    #[serde(serialize_with = "comma_separated")]
+    #[builder(default, setter(strip_option))]
+    producers: Option<Vec<ProducerId>>,
+    /// Filter by starting date.
+    #[builder(
+        setter(custom),
+        field(
+            ty = "Option<Result<PartialDate, DateError>>",
+            build = "self.start_date.clone().transpose()?"
+        )
+    )]
+    start_date: Option<PartialDate>,
+    /// Filter by ending date.
+    #[builder(
+        setter(custom),
+        field(
+            ty = "Option<Result<PartialDate, DateError>>",
+            build = "self.end_date.clone().transpose()?"
+        )
+    )]
+    end_date: Option<PartialDate>,
+}
+
+impl AnimeSearchBuilder<'_> {
+    /// Filter by starting date.
+    ///
+    /// Accepts a [`PartialDate`] or a `YYYY[-MM[-DD]]` string. An invalid date
+    /// is reported when the endpoint is built.
+    pub fn start_date<D>(&mut self, date: D) -> &mut Self
+    where
+        D: TryInto<PartialDate>,
+        D::Error: Into<DateError>,
+    {
+        self.start_date = Some(date.try_into().map_err(Into::into));
+        self
+    }
+
+    /// Filter by ending date.
+    ///
+    /// Accepts a [`PartialDate`] or a `YYYY[-MM[-DD]]` string. An invalid date
+    /// is reported when the endpoint is built.
+    pub fn end_date<D>(&mut self, date: D) -> &mut Self
+    where
+        D: TryInto<PartialDate>,
+        D::Error: Into<DateError>,
+    {
+        self.end_date = Some(date.try_into().map_err(Into::into));
+        self
+    }
+}
+
+impl AnimeSearchBuilder<'_> {
+    fn validate(&self) -> Result<(), AnimeSearchBuilderError> {
+        let scores = [self.score, self.min_score, self.max_score];
+        for score in scores.into_iter().flatten().flatten() {
+            if !(0.0..=10.0).contains(&score) {
+                return Err(AnimeSearchBuilderError::Score(score));
+            }
+        }
+        if let (Some(Some(min)), Some(Some(max))) = (self.min_score, self.max_score)
+            && min > max
+        {
+            return Err(AnimeSearchBuilderError::ScoreRange { min, max });
+        }
+        if let Some(Some(letter)) = &self.letter
+            && letter.chars().count() != 1
+        {
+            return Err(AnimeSearchBuilderError::Letter(letter.to_string()));
+        }
+        if let Some(Some(limit)) = self.limit
+            && !(1..=MAX_LIMIT).contains(&limit)
+        {
+            return Err(AnimeSearchBuilderError::Limit(limit));
+        }
+        Ok(())
+    }
+}
+
+/// Errors that occur when building an [`AnimeSearch`].
+#[derive(Debug, Clone, PartialEq, Error)]
+#[non_exhaustive]
+pub enum AnimeSearchBuilderError {
+    /// A required field was not set.
+    #[error("`{0}` must be initialized")]
+    UninitializedField(&'static str),
+    /// A date filter is not a valid date.
+    #[error("invalid date filter: {0}")]
+    Date(#[from] DateError),
+    /// A score filter is outside of the `0..=10` range.
+    #[error("score must be between 0 and 10, got {0}")]
+    Score(f32),
+    /// The minimum score is greater than the maximum score.
+    #[error("min_score ({min}) is greater than max_score ({max})")]
+    ScoreRange {
+        /// The minimum score.
+        min: f32,
+        /// The maximum score.
+        max: f32,
+    },
+    /// The letter filter is not a single character.
+    #[error("letter must be a single character, got `{0}`")]
+    Letter(String),
+    /// The limit is outside of the range allowed by Jikan.
+    #[error("limit must be between 1 and {MAX_LIMIT}, got {0}")]
+    Limit(u32),
+}
+
+impl From<UninitializedFieldError> for AnimeSearchBuilderError {
+    fn from(err: UninitializedFieldError) -> Self {
+        Self::UninitializedField(err.field_name())
+    }
 }
 
 impl<'a> AnimeSearch<'a> {
@@ -146,3 +296,198 @@ impl Endpoint for AnimeSearch<'_> {
 }
 
 impl Pageable for AnimeSearch<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query_string(search: &AnimeSearch) -> String {
+        QueryParams::with(search).unwrap().finish()
+    }
+
+    #[test]
+    fn empty_search() {
+        let search = AnimeSearch::builder().build().unwrap();
+        assert_eq!(query_string(&search), "");
+    }
+
+    #[test]
+    fn flags_only_when_set() {
+        let search = AnimeSearch::builder()
+            .unapproved(false)
+            .sfw(false)
+            .build()
+            .unwrap();
+        assert_eq!(query_string(&search), "");
+
+        let search = AnimeSearch::builder()
+            .unapproved(true)
+            .sfw(true)
+            .build()
+            .unwrap();
+        assert_eq!(query_string(&search), "unapproved=true&sfw=true");
+    }
+
+    #[test]
+    fn empty_lists_are_skipped() {
+        let search = AnimeSearch::builder()
+            .genres(vec![])
+            .genres_exclude(vec![GenreId::HORROR])
+            .producers(vec![])
+            .build()
+            .unwrap();
+        assert_eq!(query_string(&search), "genres_exclude=14");
+    }
+
+    #[test]
+    fn all_parameters() {
+        let search = AnimeSearch::builder()
+            .unapproved(true)
+            .page(2u32)
+            .limit(25u32)
+            .query("cowboy bebop")
+            .query_type(AnimeType::TvSpecial)
+            .score(8.5)
+            .min_score(7.0)
+            .max_score(9.0)
+            .status(AnimeStatus::Complete)
+            .rating(AnimeRating::R17)
+            .sfw(true)
+            .genres(vec![GenreId::ACTION, GenreId::SCI_FI])
+            .genres_exclude(vec![GenreId::HORROR])
+            .order_by(AnimeOrderBy::ScoredBy)
+            .sorting(AnimeSorting::Desc)
+            .letter("c")
+            .producers(vec![ProducerId(14), ProducerId(23)])
+            .start_date("1998-04")
+            .end_date("1999-04-24")
+            .build()
+            .unwrap();
+        assert_eq!(
+            query_string(&search),
+            "unapproved=true&page=2&limit=25&q=cowboy+bebop&type=tv_special&score=8.5&min_score=7.\
+             0&max_score=9.0&status=complete&rating=r17&sfw=true&genres=1%2C24&genres_exclude=14&\
+             order_by=scored_by&sort=desc&letter=c&producers=14%2C23&start_date=1998-04&\
+             end_date=1999-04-24"
+        );
+    }
+
+    #[test]
+    fn enum_values() {
+        let ratings = [
+            (AnimeRating::G, "g"),
+            (AnimeRating::PG, "pg"),
+            (AnimeRating::PG13, "pg13"),
+            (AnimeRating::R17, "r17"),
+            (AnimeRating::R, "r"),
+            (AnimeRating::Rx, "rx"),
+        ];
+        for (rating, expected) in ratings {
+            let search = AnimeSearch::builder().rating(rating).build().unwrap();
+            assert_eq!(query_string(&search), format!("rating={expected}"));
+        }
+
+        let types = [
+            (AnimeType::Tv, "tv"),
+            (AnimeType::Movie, "movie"),
+            (AnimeType::Ova, "ova"),
+            (AnimeType::Special, "special"),
+            (AnimeType::Ona, "ona"),
+            (AnimeType::Music, "music"),
+            (AnimeType::Cm, "cm"),
+            (AnimeType::Pv, "pv"),
+            (AnimeType::TvSpecial, "tv_special"),
+        ];
+        for (query_type, expected) in types {
+            let search = AnimeSearch::builder()
+                .query_type(query_type)
+                .build()
+                .unwrap();
+            assert_eq!(query_string(&search), format!("type={expected}"));
+        }
+
+        let search = AnimeSearch::builder()
+            .status(AnimeStatus::Upcoming)
+            .order_by(AnimeOrderBy::MalId)
+            .sorting(AnimeSorting::Asc)
+            .build()
+            .unwrap();
+        assert_eq!(
+            query_string(&search),
+            "status=upcoming&order_by=mal_id&sort=asc"
+        );
+    }
+
+    #[test]
+    fn rejects_scores_out_of_range() {
+        for score in [-0.1, 10.5] {
+            let err = AnimeSearch::builder().score(score).build().unwrap_err();
+            assert_eq!(err, AnimeSearchBuilderError::Score(score));
+            let err = AnimeSearch::builder().min_score(score).build().unwrap_err();
+            assert_eq!(err, AnimeSearchBuilderError::Score(score));
+            let err = AnimeSearch::builder().max_score(score).build().unwrap_err();
+            assert_eq!(err, AnimeSearchBuilderError::Score(score));
+        }
+        assert!(AnimeSearch::builder().score(0.0).build().is_ok());
+        assert!(AnimeSearch::builder().score(10.0).build().is_ok());
+    }
+
+    #[test]
+    fn rejects_min_score_above_max_score() {
+        let err = AnimeSearch::builder()
+            .min_score(8.0)
+            .max_score(7.5)
+            .build()
+            .unwrap_err();
+        assert_eq!(err, AnimeSearchBuilderError::ScoreRange {
+            min: 8.0,
+            max: 7.5
+        });
+        assert!(
+            AnimeSearch::builder()
+                .min_score(7.5)
+                .max_score(7.5)
+                .build()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn rejects_letters_of_other_length() {
+        for letter in ["", "ab"] {
+            let err = AnimeSearch::builder().letter(letter).build().unwrap_err();
+            assert_eq!(err, AnimeSearchBuilderError::Letter(letter.to_owned()));
+        }
+        assert!(AnimeSearch::builder().letter("é").build().is_ok());
+    }
+
+    #[test]
+    fn rejects_limit_out_of_range() {
+        for limit in [0u32, 26] {
+            let err = AnimeSearch::builder().limit(limit).build().unwrap_err();
+            assert_eq!(err, AnimeSearchBuilderError::Limit(limit));
+        }
+        assert!(AnimeSearch::builder().limit(1u32).build().is_ok());
+        assert!(AnimeSearch::builder().limit(25u32).build().is_ok());
+    }
+
+    #[test]
+    fn iterator_page_overrides_search_page() {
+        let search = AnimeSearch::builder()
+            .page(7u32)
+            .limit(10u32)
+            .build()
+            .unwrap();
+        let state = crate::api::page::InnerState {
+            endpoint: &search,
+            page: 3,
+            limit: Some(5),
+        };
+        let params = search
+            .query_params()
+            .unwrap()
+            .override_with(&state)
+            .unwrap();
+        assert_eq!(params.finish(), "page=3&limit=5");
+    }
+}
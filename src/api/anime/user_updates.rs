@@ -2,9 +2,12 @@ use std::borrow::Cow;
 
 use http::Method;
 
-use crate::api::{
-    endpoint::Endpoint,
-    page::Pageable,
+use crate::{
+    api::{
+        endpoint::Endpoint,
+        page::Pageable,
+    },
+    types::AnimeId,
 };
 
 /// Retrieves a list of users who have added/updated/removed.
@@ -13,7 +16,7 @@ use crate::api::{
 #[builder(setter(into))]
 pub struct UserUpdates {
     #[doc = r"`ID` of the anime"]
-    id: u32,
+    id: AnimeId,
 }
 
 impl UserUpdates {
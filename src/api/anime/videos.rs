@@ -2,14 +2,17 @@ use std::borrow::Cow;
 
 use http::Method;
 
-use crate::api::endpoint::Endpoint;
+use crate::{
+    api::endpoint::Endpoint,
+    types::AnimeId,
+};
 
 /// Retrieves videos related to the entry.
 #[derive(Debug, Clone, Builder)]
 #[builder(setter(into))]
 pub struct Videos {
     #[doc = r"`ID` of the anime"]
-    id: u32,
+    id: AnimeId,
 }
 
 impl Videos {
@@ -28,3 +31,22 @@ impl Endpoint for Videos {
         format!("/anime/{}/videos", self.id).into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        JikanApiClient,
+        api::utils,
+    };
+
+    #[test]
+    fn url() {
+        let endpoint = Videos::builder().id(1u32).build().unwrap();
+        let (req, _) = utils::build_request(&endpoint, &JikanApiClient::new()).unwrap();
+        assert_eq!(
+            req.uri_ref().unwrap(),
+            "https://api.jikan.moe/v4/anime/1/videos"
+        );
+    }
+}
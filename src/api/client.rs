@@ -8,7 +8,13 @@ use http::{
 };
 use url::Url;
 
-use super::error::ApiError;
+use super::{
+    decode::{
+        DecodeMode,
+        DecodeWarning,
+    },
+    error::ApiError,
+};
 
 /// A parent trait representing a client which can communicate with jikan.moe
 pub trait RestClient {
@@ -19,6 +25,20 @@ pub trait RestClient {
     ///
     /// This method adds the hostname for the target api.
     fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>>;
+
+    /// How responses are deserialized.
+    fn decode_mode(&self) -> DecodeMode {
+        DecodeMode::Default
+    }
+
+    /// Called with the warnings raised while deserializing a response.
+    ///
+    /// Logs the warnings by default.
+    fn decode_warnings(&self, warnings: Vec<DecodeWarning>) {
+        for warning in warnings {
+            log::warn!("{warning}");
+        }
+    }
 }
 
 /// A trait representing a blocking client which can communicate with jikan.moe
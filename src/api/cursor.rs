@@ -0,0 +1,234 @@
+use std::borrow::Cow;
+
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    de,
+};
+
+use super::{
+    RestClient,
+    endpoint::Endpoint,
+    error::BodyError,
+    page::{
+        Pageable,
+        PagedIter,
+    },
+    query_params::QueryParams,
+};
+
+/// Position of an iteration over a paginated endpoint.
+///
+/// A cursor holds everything needed to continue an iteration, so it can be
+/// persisted (e.g. as JSON) and used later to resume from where it stopped,
+/// even in another process. Cursors are obtained from the `cursor` methods of
+/// the paged iterators.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageCursor {
+    /// Path of the endpoint.
+    pub endpoint: String,
+    /// Query string of the endpoint, without the `page` and `limit` parameters.
+    pub query: String,
+    /// Page to resume from, numbered from 1.
+    #[serde(deserialize_with = "page_number")]
+    pub page: u32,
+    /// Number of items of `page` which were already consumed.
+    #[serde(default)]
+    pub skip: usize,
+    /// Number of items requested per page, either set on the iterator or as
+    /// the `limit` of the endpoint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u32>,
+    /// Number of pages left to fetch, when the iteration is limited.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_pages: Option<u32>,
+    /// Number of items left to yield, when the iteration is limited.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_items: Option<usize>,
+}
+
+fn page_number<'de, D>(deserializer: D) -> Result<u32, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match u32::deserialize(deserializer)? {
+        0 => Err(de::Error::invalid_value(
+            de::Unexpected::Unsigned(0),
+            &"a page number from 1",
+        )),
+        page => Ok(page),
+    }
+}
+
+impl PageCursor {
+    pub(crate) fn new<E>(
+        endpoint: &E,
+        page: u32,
+        skip: usize,
+        per_page: Option<u32>,
+    ) -> Result<Self, BodyError>
+    where
+        E: Endpoint,
+    {
+        let query = endpoint.query_params()?.finish();
+        let mut params = QueryParams::default();
+        let mut limit = None;
+        for (key, value) in form_urlencoded::parse(query.as_bytes()) {
+            match &*key {
+                "page" => {}
+                "limit" => limit = value.parse().ok(),
+                _ => {
+                    params.append_pair(key, value);
+                }
+            }
+        }
+        Ok(Self {
+            endpoint: endpoint.endpoint().into_owned(),
+            query: params.finish(),
+            page,
+            skip,
+            per_page: per_page.or(limit),
+            max_pages: None,
+            max_items: None,
+        })
+    }
+
+    /// Resume the iteration described by this cursor, with the limits it
+    /// was created with.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `page` was set to 0, deserialized cursors are rejected
+    /// instead.
+    pub fn resume<'a, T, C>(&'a self, client: &'a C) -> PagedIter<'a, Self, C, T>
+    where
+        C: RestClient,
+    {
+        let mut iter = PagedIter::new(self, client)
+            .start_page(self.page)
+            .skip_items(self.skip);
+        if let Some(limit) = self.per_page {
+            iter = iter.per_page(limit);
+        }
+        if let Some(count) = self.max_pages {
+            iter = iter.max_pages(count);
+        }
+        if let Some(count) = self.max_items {
+            iter = iter.max_items(count);
+        }
+        iter
+    }
+}
+
+impl Endpoint for PageCursor {
+    fn endpoint(&self) -> Cow<'static, str> {
+        self.endpoint.clone().into()
+    }
+
+    fn query_params(&self) -> Result<QueryParams<'_>, BodyError> {
+        let mut params = QueryParams::default();
+        params.extend_pairs(form_urlencoded::parse(self.query.as_bytes()));
+        Ok(params)
+    }
+}
+
+impl Pageable for PageCursor {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{
+        PagedEndpointExt,
+        anime::AnimeSearch,
+        page::tests::PagesClient,
+    };
+
+    #[test]
+    fn strips_paging_parameters() {
+        let search = AnimeSearch::builder()
+            .query("bebop")
+            .page(3u32)
+            .limit(2u32)
+            .build()
+            .unwrap();
+        let cursor = PageCursor::new(&search, 1, 0, None).unwrap();
+        assert_eq!(cursor.query, "q=bebop");
+        assert_eq!(cursor.per_page, Some(2));
+
+        let json = serde_json::to_string(&cursor).unwrap();
+        assert_eq!(
+            json,
+            r#"{"endpoint":"/anime","query":"q=bebop","page":1,"skip":0,"per_page":2}"#
+        );
+        assert_eq!(serde_json::from_str::<PageCursor>(&json).unwrap(), cursor);
+    }
+
+    #[test]
+    fn resumes_where_iteration_stopped() {
+        let client = PagesClient::new(3);
+        let search = AnimeSearch::builder()
+            .query("bebop")
+            .limit(2u32)
+            .build()
+            .unwrap();
+        let mut iter = search.iter::<u32, _>(&client);
+        let first: Vec<u32> = iter.by_ref().take(3).map(Result::unwrap).collect();
+        assert_eq!(first, [1, 2, 3]);
+
+        let cursor = iter.cursor().unwrap();
+        assert_eq!((cursor.page, cursor.skip), (2, 1));
+        let json = serde_json::to_string(&cursor).unwrap();
+        let cursor: PageCursor = serde_json::from_str(&json).unwrap();
+        let rest: Vec<u32> = cursor.resume(&client).map(Result::unwrap).collect();
+        assert_eq!(rest, [4, 5, 6]);
+        assert_eq!(client.queries()[2..], [
+            "q=bebop&page=2&limit=2",
+            "q=bebop&page=3&limit=2",
+        ]);
+    }
+
+    #[test]
+    fn skips_consumed_items_of_page() {
+        let client = PagesClient::new(3);
+        let cursor = PageCursor {
+            endpoint: "/anime".into(),
+            query: "q=bebop".into(),
+            page: 3,
+            skip: 1,
+            per_page: Some(2),
+            max_pages: None,
+            max_items: None,
+        };
+        let rest: Vec<u32> = cursor.resume(&client).map(Result::unwrap).collect();
+        assert_eq!(rest, [6]);
+    }
+
+    #[test]
+    fn rejects_page_zero() {
+        let json = r#"{"endpoint":"/anime","query":"","page":0}"#;
+        let err = serde_json::from_str::<PageCursor>(json).unwrap_err();
+        assert!(err.to_string().contains("a page number from 1"), "{err}");
+    }
+
+    #[test]
+    fn keeps_iteration_limits() {
+        let client = PagesClient::new(3);
+        let search = AnimeSearch::builder().limit(2u32).build().unwrap();
+        let mut iter = search.iter::<u32, _>(&client).max_items(5);
+        let first: Vec<u32> = iter.by_ref().take(3).map(Result::unwrap).collect();
+        assert_eq!(first, [1, 2, 3]);
+
+        let cursor = iter.cursor().unwrap();
+        assert_eq!(cursor.max_items, Some(2));
+        let rest: Vec<u32> = cursor.resume(&client).map(Result::unwrap).collect();
+        assert_eq!(rest, [4, 5]);
+
+        let mut pages = search.iter::<u32, _>(&client).max_pages(2).pages();
+        pages.next().unwrap().unwrap();
+        let cursor = pages.cursor().unwrap();
+        assert_eq!((cursor.page, cursor.max_pages), (2, Some(1)));
+        let rest: Vec<u32> = cursor.resume(&client).map(Result::unwrap).collect();
+        assert_eq!(rest, [3, 4]);
+    }
+}
@@ -0,0 +1,862 @@
+use std::{
+    cell::RefCell,
+    collections::{
+        BTreeSet,
+        HashMap,
+    },
+    fmt,
+    sync::Arc,
+};
+
+use serde::{
+    Deserialize,
+    Deserializer,
+    de::{
+        self,
+        DeserializeOwned,
+        DeserializeSeed,
+        MapAccess,
+        SeqAccess,
+        Visitor,
+        value::BorrowedStrDeserializer,
+    },
+};
+use serde_json::Value;
+
+use super::Root;
+
+/// How responses are deserialized, see [`super::RestClient::decode_mode`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DecodeMode {
+    /// Any malformed value fails the whole response, unknown fields are
+    /// ignored.
+    #[default]
+    Default,
+    /// Malformed list items are skipped instead of failing the whole
+    /// response. Skipped items and unknown fields are reported as
+    /// [`DecodeWarning`]s.
+    Lenient,
+    /// Any unknown field fails the whole response, as if every type had
+    /// `#[serde(deny_unknown_fields)]`. Meant for tests noticing data the
+    /// crate does not model yet.
+    Strict,
+}
+
+/// Problem found while decoding a response in [`DecodeMode::Lenient`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodeWarning {
+    /// URL of the response.
+    pub url: http::Uri,
+    /// Path of the value in the response, e.g. `data[3].score`.
+    pub path: String,
+    /// What was wrong with the value.
+    pub kind: DecodeWarningKind,
+}
+
+/// Kind of a [`DecodeWarning`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DecodeWarningKind {
+    /// The field is not known to the crate and was ignored.
+    UnknownField,
+    /// The list item could not be deserialized and was skipped.
+    SkippedItem {
+        /// Error raised while deserializing the item.
+        error: String,
+    },
+}
+
+impl fmt::Display for DecodeWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            DecodeWarningKind::UnknownField => {
+                write!(f, "unknown field `{}` in {}", self.path, self.url)
+            }
+            DecodeWarningKind::SkippedItem { error } => {
+                write!(f, "skipped `{}` in {}: {error}", self.path, self.url)
+            }
+        }
+    }
+}
+
+/// Callback the clients hand their [`DecodeWarning`]s to, logging them by
+/// default.
+#[derive(Clone, Default)]
+pub(crate) struct WarningHandler(Option<HandlerFn>);
+
+type HandlerFn = Arc<dyn Fn(&DecodeWarning) + Send + Sync>;
+
+impl WarningHandler {
+    pub(crate) fn new<F>(handler: F) -> Self
+    where
+        F: Fn(&DecodeWarning) + Send + Sync + 'static,
+    {
+        Self(Some(Arc::new(handler)))
+    }
+
+    pub(crate) fn handle(&self, warnings: Vec<DecodeWarning>) {
+        for warning in &warnings {
+            match &self.0 {
+                Some(handler) => handler(warning),
+                None => log::warn!("{warning}"),
+            }
+        }
+    }
+}
+
+impl fmt::Debug for WarningHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(_) => f.write_str("WarningHandler(..)"),
+            None => f.write_str("WarningHandler(log)"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+fn fmt_path(path: &[Segment]) -> String {
+    let mut out = String::new();
+    for segment in path {
+        match segment {
+            Segment::Key(key) => {
+                if !out.is_empty() {
+                    out.push('.');
+                }
+                out.push_str(key);
+            }
+            Segment::Index(index) => {
+                out.push('[');
+                out.push_str(&index.to_string());
+                out.push(']');
+            }
+        }
+    }
+    out
+}
+
+/// Path of the value being deserialized, built on the stack.
+enum Path<'a> {
+    Root,
+    Key(&'a Path<'a>, &'a str),
+    Index(&'a Path<'a>, usize),
+}
+
+impl Path<'_> {
+    fn segments(&self) -> Vec<Segment> {
+        match self {
+            Path::Root => Vec::new(),
+            Path::Key(parent, key) => {
+                let mut segments = parent.segments();
+                segments.push(Segment::Key((*key).to_owned()));
+                segments
+            }
+            Path::Index(parent, index) => {
+                let mut segments = parent.segments();
+                segments.push(Segment::Index(*index));
+                segments
+            }
+        }
+    }
+}
+
+/// What the previous passes learned about the lists of the response, keyed
+/// by the path of the list.
+#[derive(Default)]
+struct Lists {
+    /// Items skipped so far.
+    skipped: HashMap<Vec<Segment>, BTreeSet<usize>>,
+    /// Lists the previous pass stopped reading after skipping an item, with
+    /// the index to resume from.
+    pending: HashMap<Vec<Segment>, usize>,
+    /// Items holding a pending list.
+    leads: HashMap<Vec<Segment>, BTreeSet<usize>>,
+}
+
+/// What a pass found, dropped again when the item it was found in is
+/// skipped.
+#[derive(Default)]
+struct Found {
+    /// Path of the list, index and error of each skipped item.
+    skipped: Vec<(Vec<Segment>, usize, String)>,
+    /// Path of each unknown field, with the name of the struct holding it.
+    unknown: Vec<(Vec<Segment>, Option<&'static str>)>,
+}
+
+impl Found {
+    fn mark(&self) -> (usize, usize) {
+        (self.skipped.len(), self.unknown.len())
+    }
+
+    fn rollback(&mut self, (skipped, unknown): (usize, usize)) {
+        self.skipped.truncate(skipped);
+        self.unknown.truncate(unknown);
+    }
+}
+
+/// One deserialization of the response.
+struct Pass<'a> {
+    /// Whether list items failing to deserialize are skipped.
+    lenient: bool,
+    /// Whether only the pending lists are read, see [`decode_lenient`].
+    resume: bool,
+    lists: &'a Lists,
+    found: RefCell<Found>,
+}
+
+impl<'a> Pass<'a> {
+    fn new(lenient: bool, resume: bool, lists: &'a Lists) -> Self {
+        Self {
+            lenient,
+            resume,
+            lists,
+            found: RefCell::default(),
+        }
+    }
+
+    fn run<T>(&self, value: &Value) -> Result<Root<T>, serde_json::Error>
+    where
+        T: DeserializeOwned,
+    {
+        Root::deserialize(Tracked {
+            value,
+            path: &Path::Root,
+            pass: self,
+            fresh: !self.resume,
+            owner: None,
+        })
+    }
+}
+
+/// Deserializer of a [`Value`] recording the unknown fields and, in lenient
+/// passes, skipping the list items failing to deserialize.
+///
+/// Values deserialized through `deserialize_any`, e.g. by `#[serde(flatten)]`
+/// or untagged enums, are handed over to [`Value`] as a whole.
+struct Tracked<'de, 'a> {
+    value: &'de Value,
+    path: &'a Path<'a>,
+    pass: &'a Pass<'a>,
+    /// Whether no previous pass read the value.
+    fresh: bool,
+    /// Name of the struct holding the value.
+    owner: Option<&'static str>,
+}
+
+impl<'de> Tracked<'de, '_> {
+    fn visit_array<V>(
+        self,
+        items: &'de [Value],
+        list: bool,
+        visitor: V,
+    ) -> Result<V::Value, serde_json::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let mut seq = Seq {
+            items,
+            index: 0,
+            path: self.path,
+            pass: self.pass,
+            fresh: self.fresh,
+            list: (list && self.pass.lenient).then(|| self.path.segments()),
+        };
+        let value = visitor.visit_seq(&mut seq)?;
+        if seq.list.is_none() && seq.index < items.len() {
+            return Err(de::Error::invalid_length(
+                items.len(),
+                &"fewer elements in array",
+            ));
+        }
+        Ok(value)
+    }
+
+    fn visit_object<V>(
+        self,
+        entries: &'de serde_json::Map<String, Value>,
+        name: Option<&'static str>,
+        visitor: V,
+    ) -> Result<V::Value, serde_json::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let mut map = Map {
+            entries: entries.iter(),
+            value: None,
+            path: self.path,
+            pass: self.pass,
+            fresh: self.fresh,
+            name,
+        };
+        let value = visitor.visit_map(&mut map)?;
+        if map.entries.len() != 0 {
+            return Err(de::Error::invalid_length(
+                entries.len(),
+                &"fewer elements in map",
+            ));
+        }
+        Ok(value)
+    }
+}
+
+macro_rules! forward_to_value {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: Visitor<'de>,
+            {
+                self.value.$method(visitor)
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for Tracked<'de, '_> {
+    type Error = serde_json::Error;
+
+    forward_to_value! {
+        deserialize_any deserialize_bool deserialize_i8 deserialize_i16
+        deserialize_i32 deserialize_i64 deserialize_i128 deserialize_u8
+        deserialize_u16 deserialize_u32 deserialize_u64 deserialize_u128
+        deserialize_f32 deserialize_f64 deserialize_char deserialize_str
+        deserialize_string deserialize_bytes deserialize_byte_buf
+        deserialize_unit deserialize_identifier
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_unit_struct<V>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.value.deserialize_unit_struct(name, visitor)
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::Array(items) => self.visit_array(items, true, visitor),
+            value => value.deserialize_seq(visitor),
+        }
+    }
+
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::Array(items) => self.visit_array(items, false, visitor),
+            value => value.deserialize_tuple(len, visitor),
+        }
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::Array(items) => self.visit_array(items, false, visitor),
+            value => value.deserialize_tuple_struct(name, len, visitor),
+        }
+    }
+
+    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::Object(entries) => self.visit_object(entries, None, visitor),
+            value => value.deserialize_map(visitor),
+        }
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::Object(entries) => self.visit_object(entries, Some(name), visitor),
+            value => value.deserialize_struct(name, fields, visitor),
+        }
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.value.deserialize_enum(name, variants, visitor)
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.pass
+            .found
+            .borrow_mut()
+            .unknown
+            .push((self.path.segments(), self.owner));
+        visitor.visit_unit()
+    }
+}
+
+struct Seq<'de, 'a> {
+    items: &'de [Value],
+    index: usize,
+    path: &'a Path<'a>,
+    pass: &'a Pass<'a>,
+    fresh: bool,
+    /// Path of the list, when its items may be skipped.
+    list: Option<Vec<Segment>>,
+}
+
+impl Seq<'_, '_> {
+    /// Index of the next item to read, and whether it is fresh.
+    fn next_item(&self) -> Option<(usize, bool)> {
+        let Some(list) = &self.list else {
+            return (self.index < self.items.len()).then_some((self.index, self.fresh));
+        };
+        let lists = self.pass.lists;
+        let mut index = self.index;
+        loop {
+            let (next, fresh) = if self.fresh {
+                (index, true)
+            } else {
+                let resume = lists
+                    .pending
+                    .get(list)
+                    .map_or(self.items.len(), |&resume| resume.max(index));
+                match lists
+                    .leads
+                    .get(list)
+                    .and_then(|leads| leads.range(index..resume).next())
+                {
+                    Some(&lead) => (lead, false),
+                    None => (resume, true),
+                }
+            };
+            if next >= self.items.len() {
+                return None;
+            }
+            if !lists
+                .skipped
+                .get(list)
+                .is_some_and(|skipped| skipped.contains(&next))
+            {
+                return Some((next, fresh));
+            }
+            index = next + 1;
+        }
+    }
+}
+
+impl<'de> SeqAccess<'de> for Seq<'de, '_> {
+    type Error = serde_json::Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        let Some((index, fresh)) = self.next_item() else {
+            self.index = self.items.len();
+            return Ok(None);
+        };
+        self.index = index + 1;
+        let path = Path::Index(self.path, index);
+        let item = Tracked {
+            value: &self.items[index],
+            path: &path,
+            pass: self.pass,
+            fresh,
+            owner: None,
+        };
+        let Some(list) = self.list.as_ref().filter(|_| fresh) else {
+            return seed.deserialize(item).map(Some);
+        };
+
+        let mark = self.pass.found.borrow().mark();
+        match seed.deserialize(item) {
+            Ok(value) => Ok(Some(value)),
+            Err(err) => {
+                let mut found = self.pass.found.borrow_mut();
+                found.rollback(mark);
+                found.skipped.push((list.clone(), index, err.to_string()));
+                // The seed is spent, the next pass resumes after the item.
+                self.index = self.items.len();
+                Ok(None)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        match self.list {
+            Some(_) => None,
+            None => Some(self.items.len() - self.index),
+        }
+    }
+}
+
+struct Map<'de, 'a> {
+    entries: serde_json::map::Iter<'de>,
+    value: Option<(&'de str, &'de Value)>,
+    path: &'a Path<'a>,
+    pass: &'a Pass<'a>,
+    fresh: bool,
+    /// Name of the struct, if the map is one.
+    name: Option<&'static str>,
+}
+
+impl<'de> MapAccess<'de> for Map<'de, '_> {
+    type Error = serde_json::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        let Some((key, value)) = self.entries.next() else {
+            return Ok(None);
+        };
+        self.value = Some((key, value));
+        seed.deserialize(BorrowedStrDeserializer::new(key))
+            .map(Some)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let (key, value) = self
+            .value
+            .take()
+            .ok_or_else(|| de::Error::custom("value is missing"))?;
+        seed.deserialize(Tracked {
+            value,
+            path: &Path::Key(self.path, key),
+            pass: self.pass,
+            fresh: self.fresh,
+            owner: self.name,
+        })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.entries.len())
+    }
+}
+
+pub(crate) enum DecodeError {
+    /// A value could not be deserialized.
+    Data(serde_json::Error),
+    /// Path of each unknown field found in [`DecodeMode::Strict`], with the
+    /// name of the struct holding it.
+    UnknownFields(Vec<(String, Option<&'static str>)>),
+}
+
+/// Deserializes a response according to `mode`.
+pub(crate) fn decode<T>(
+    value: &Value,
+    mode: DecodeMode,
+    url: &http::Uri,
+) -> Result<(Root<T>, Vec<DecodeWarning>), DecodeError>
+where
+    T: DeserializeOwned,
+{
+    match mode {
+        DecodeMode::Default => Root::deserialize(value)
+            .map(|root| (root, Vec::new()))
+            .map_err(DecodeError::Data),
+        DecodeMode::Lenient => decode_lenient(value, url).map_err(DecodeError::Data),
+        DecodeMode::Strict => decode_strict(value).map(|root| (root, Vec::new())),
+    }
+}
+
+fn decode_strict<T>(value: &Value) -> Result<Root<T>, DecodeError>
+where
+    T: DeserializeOwned,
+{
+    let lists = Lists::default();
+    let pass = Pass::new(false, false, &lists);
+    let root = pass.run(value).map_err(DecodeError::Data)?;
+    let unknown = pass.found.into_inner().unknown;
+    if !unknown.is_empty() {
+        let fields = unknown
+            .iter()
+            .map(|(path, owner)| (fmt_path(path), *owner))
+            .collect();
+        return Err(DecodeError::UnknownFields(fields));
+    }
+    Ok(root)
+}
+
+/// Deserializes the response, skipping the innermost list items which fail
+/// to.
+///
+/// Serde cannot retry an item, so a pass stops reading a list at its first
+/// failing item. The next passes only read the rest of these lists, along
+/// with the items leading to them, until none has a failing item left. A
+/// last pass then reads the whole response without the skipped items, so
+/// each item is read a few times at most however many are skipped.
+fn decode_lenient<T>(
+    value: &Value,
+    url: &http::Uri,
+) -> Result<(Root<T>, Vec<DecodeWarning>), serde_json::Error>
+where
+    T: DeserializeOwned,
+{
+    let mut lists = Lists::default();
+    let mut warnings = Vec::new();
+    let mut resume = false;
+    loop {
+        let pass = Pass::new(true, resume, &lists);
+        let result = pass.run(value);
+        let found = pass.found.into_inner();
+        match result {
+            Ok(root) if !resume && found.skipped.is_empty() => {
+                warnings.extend(found.unknown.iter().map(|(path, _)| DecodeWarning {
+                    url: url.clone(),
+                    path: fmt_path(path),
+                    kind: DecodeWarningKind::UnknownField,
+                }));
+                return Ok((root, warnings));
+            }
+            Ok(_) => {}
+            Err(err) if !resume => return Err(err),
+            // Reading part of a list may upset the item holding it, read the
+            // whole response again instead.
+            Err(_) => {
+                lists.pending.clear();
+                lists.leads.clear();
+                resume = false;
+                continue;
+            }
+        }
+
+        lists.pending.clear();
+        lists.leads.clear();
+        for (list, index, error) in found.skipped {
+            let mut path = list.clone();
+            path.push(Segment::Index(index));
+            warnings.push(DecodeWarning {
+                url: url.clone(),
+                path: fmt_path(&path),
+                kind: DecodeWarningKind::SkippedItem { error },
+            });
+            for (at, segment) in list.iter().enumerate() {
+                if let Segment::Index(lead) = segment {
+                    lists
+                        .leads
+                        .entry(list[..at].to_vec())
+                        .or_default()
+                        .insert(*lead);
+                }
+            }
+            lists.pending.insert(list.clone(), index + 1);
+            lists.skipped.entry(list).or_default().insert(index);
+        }
+        resume = !lists.pending.is_empty();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Item {
+        id: u32,
+        #[serde(default)]
+        tags: Vec<String>,
+    }
+
+    fn lenient(value: Value) -> Result<(Root<Vec<Item>>, Vec<DecodeWarning>), serde_json::Error> {
+        decode_lenient(&value, &http::Uri::from_static("/anime"))
+    }
+
+    fn paths(warnings: &[DecodeWarning]) -> Vec<(&str, bool)> {
+        warnings
+            .iter()
+            .map(|warning| {
+                let skipped = matches!(warning.kind, DecodeWarningKind::SkippedItem { .. });
+                (warning.path.as_str(), skipped)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn skips_malformed_items() {
+        let value = json!({
+            "data": [
+                { "id": 1 },
+                { "id": "two" },
+                { "id": 3, "extra": true },
+                { "id": 4, "tags": [1] },
+                { "id": 5, "tags": ["a"], "more": null },
+            ],
+        });
+        assert!(decode::<Vec<Item>>(&value, DecodeMode::Default, &http::Uri::default()).is_err());
+
+        let (root, warnings) = lenient(value).unwrap();
+        let ids: Vec<_> = root.data.iter().map(|item| item.id).collect();
+        assert_eq!(ids, [1, 3, 4, 5]);
+        // Only the innermost malformed item is skipped.
+        assert!(root.data[2].tags.is_empty());
+        assert_eq!(paths(&warnings), [
+            ("data[1]", true),
+            ("data[3].tags[0]", true),
+            ("data[2].extra", false),
+            ("data[4].more", false),
+        ]);
+        assert!(warnings[0].to_string().contains("/anime"));
+    }
+
+    #[test]
+    fn skips_every_malformed_item() {
+        let data: Vec<_> = (0..100)
+            .map(|id| match id % 3 {
+                0 => json!({ "id": id, "tags": ["a", 1, "b", 2] }),
+                1 => json!({ "id": "malformed" }),
+                _ => json!({ "id": id }),
+            })
+            .collect();
+        let (root, warnings) = lenient(json!({ "data": data })).unwrap();
+        assert_eq!(root.data.len(), 67);
+        assert!(root.data.iter().all(|item| item.id % 3 != 1));
+        assert_eq!(root.data[0].tags, ["a", "b"]);
+        assert_eq!(warnings.len(), 33 + 34 * 2);
+        assert_eq!(paths(&warnings[..2]), [
+            ("data[0].tags[1]", true),
+            ("data[1]", true)
+        ]);
+    }
+
+    #[test]
+    fn fails_outside_of_lists() {
+        assert!(lenient(json!({ "data": { "id": 1 } })).is_err());
+        assert!(lenient(json!({ "pagination": [] })).is_err());
+    }
+
+    #[test]
+    fn reports_unknown_fields_of_anime_full() {
+        let mut value: Value =
+            serde_json::from_str(include_str!("../../tests/fixtures/anime/anime_full.json"))
+                .unwrap();
+        value["data"]["new_field"] = json!(1);
+        value["data"]["relations"][0]["new_field"] = json!(1);
+        let (_, warnings) =
+            decode_lenient::<crate::types::anime::AnimeFull>(&value, &http::Uri::default())
+                .unwrap();
+        let paths: Vec<_> = warnings
+            .iter()
+            .map(|warning| warning.path.as_str())
+            .collect();
+        assert_eq!(paths, ["data.new_field", "data.relations[0].new_field"]);
+    }
+
+    #[test]
+    fn skips_malformed_items_of_anime_full() {
+        let mut value: Value =
+            serde_json::from_str(include_str!("../../tests/fixtures/anime/anime_full.json"))
+                .unwrap();
+        let genres = value["data"]["genres"].as_array().unwrap().len();
+        value["data"]["genres"][0]["mal_id"] = json!("x");
+        let (root, warnings) =
+            decode_lenient::<crate::types::anime::AnimeFull>(&value, &http::Uri::default())
+                .unwrap();
+        assert_eq!(root.data.genres.len(), genres - 1);
+        assert_eq!(paths(&warnings), [("data.genres[0]", true)]);
+    }
+
+    #[test]
+    fn strict_rejects_unknown_fields() {
+        let strict =
+            |value: &Value| decode::<Vec<Item>>(value, DecodeMode::Strict, &http::Uri::default());
+
+        let known = json!({ "data": [{ "id": 1, "tags": ["a"] }] });
+        assert_eq!(strict(&known).ok().unwrap().0.data[0].id, 1);
+
+        let unknown = json!({ "data": [{ "id": 1 }, { "id": 2, "extra": { "a": 1 } }], "new": 1 });
+        let Err(DecodeError::UnknownFields(fields)) = strict(&unknown) else {
+            panic!("unknown fields accepted");
+        };
+        assert_eq!(fields, [
+            ("data[1].extra".to_owned(), Some("Item")),
+            ("new".to_owned(), Some("Root")),
+        ]);
+
+        let malformed = json!({ "data": [{ "id": "one" }] });
+        assert!(matches!(strict(&malformed), Err(DecodeError::Data(_))));
+    }
+
+    #[test]
+    fn strict_rejects_unknown_fields_of_anime_full() {
+        let mut value: Value =
+            serde_json::from_str(include_str!("../../tests/fixtures/anime/anime_full.json"))
+                .unwrap();
+        let strict = |value: &Value| {
+            decode::<crate::types::anime::AnimeFull>(
+                value,
+                DecodeMode::Strict,
+                &http::Uri::default(),
+            )
+        };
+        assert!(strict(&value).is_ok());
+
+        value["data"]["brand_new_top_level"] = json!(1);
+        value["data"]["studios"][0]["new_nested"] = json!(1);
+        let Err(DecodeError::UnknownFields(fields)) = strict(&value) else {
+            panic!("unknown fields accepted");
+        };
+        assert_eq!(fields, [
+            ("data.brand_new_top_level".to_owned(), Some("AnimeFull")),
+            ("data.studios[0].new_nested".to_owned(), Some("MalUrl")),
+        ]);
+    }
+}
@@ -0,0 +1,355 @@
+//! Image downloads.
+//!
+//! [`ImageDownload`] picks a variant of any image from the responses and
+//! fetches it through a [`Client`] or [`AsyncClient`], so the timeouts of the
+//! client apply to downloads as well. Downloads are not throttled.
+
+use std::{
+    error::Error,
+    fmt::Write as _,
+    fs,
+    io,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use bytes::Bytes;
+use http::{
+    Method,
+    Request,
+    Response,
+    StatusCode,
+    header,
+};
+use sha2::{
+    Digest,
+    Sha256,
+};
+use thiserror::Error;
+
+use super::{
+    ApiError,
+    AsyncClient,
+    Client,
+};
+use crate::types::{
+    Image,
+    ImageFormat,
+    ImageSize,
+    Images,
+};
+
+/// Errors that occur while downloading an image.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum DownloadError<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    /// No variant of the image is available.
+    #[error("no image available")]
+    NoImage,
+    /// The request failed.
+    #[error("API error: {0}")]
+    Api(#[from] ApiError<E>),
+    /// The server answered with an error status.
+    #[error("HTTP error {status} downloading {url}")]
+    HttpStatus {
+        /// Status of the response.
+        status: StatusCode,
+        /// URL of the image.
+        url: url::Url,
+    },
+    /// The server did not answer with an image.
+    #[error("unexpected content type {content_type:?} downloading {url}")]
+    ContentType {
+        /// Content type of the response, if any.
+        content_type: Option<String>,
+        /// URL of the image.
+        url: url::Url,
+    },
+    /// Writing the image failed.
+    #[error("failed to write image: {0}")]
+    Io(#[from] io::Error),
+}
+
+/// A downloaded image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Download {
+    /// Variant which was downloaded.
+    pub image: Image,
+    /// Content type sent by the server, always the type of the format of
+    /// `image`.
+    pub content_type: String,
+    /// Content of the image.
+    pub bytes: Bytes,
+}
+
+impl Download {
+    /// File name derived from the SHA-256 of the content and the format of the
+    /// image, e.g. `3f2a…9c.webp`.
+    ///
+    /// The name only depends on the content, the same image downloaded twice
+    /// gets the same name.
+    pub fn file_name(&self) -> String {
+        let mut name = String::with_capacity(64 + 5);
+        for byte in Sha256::digest(&self.bytes) {
+            let _ = write!(name, "{byte:02x}");
+        }
+        name.push('.');
+        name.push_str(self.extension());
+        name
+    }
+
+    /// File extension of the format of the image, which the content type
+    /// was checked against.
+    pub fn extension(&self) -> &'static str {
+        self.image.format.extension()
+    }
+
+    /// Writes the image to `path`.
+    pub fn save_as(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, &self.bytes)
+    }
+
+    /// Writes the image into the directory `dir`, named after
+    /// [`Download::file_name`], and returns its path.
+    pub fn save_in(&self, dir: impl AsRef<Path>) -> io::Result<PathBuf> {
+        let path = dir.as_ref().join(self.file_name());
+        self.save_as(&path)?;
+        Ok(path)
+    }
+}
+
+/// Download of the best variant of an image.
+///
+/// Accepts any image type of [`crate::types::anime`] as well as a
+/// [`crate::types::anime::Trailer`]. By default the largest JPEG variant is
+/// downloaded, see [`Images::best`] for how the variant is chosen.
+#[derive(Debug, Clone)]
+pub struct ImageDownload {
+    images: Images,
+    format: ImageFormat,
+    max_size: ImageSize,
+}
+
+impl ImageDownload {
+    /// Create a download of one of `images`.
+    pub fn new(images: impl Into<Images>) -> Self {
+        Self {
+            images: images.into(),
+            format: ImageFormat::Jpg,
+            max_size: ImageSize::Maximum,
+        }
+    }
+
+    /// Preferred format of the image.
+    pub fn format(mut self, format: ImageFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Largest size to download.
+    pub fn max_size(mut self, max_size: ImageSize) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// The variant which will be downloaded.
+    pub fn image(&self) -> Option<&Image> {
+        self.images.best(self.format, self.max_size)
+    }
+
+    fn request(&self) -> Option<(&Image, http::request::Builder)> {
+        let image = self.image()?;
+        let request = Request::builder()
+            .method(Method::GET)
+            .uri(image.url.as_str())
+            .header(
+                header::ACCEPT,
+                format!("{},image/*", image.format.mime_type()),
+            );
+        Some((image, request))
+    }
+
+    /// Download the image using a blocking client.
+    #[allow(clippy::result_large_err)] // `ApiError` is returned by value across the API.
+    pub fn fetch<C>(&self, client: &C) -> Result<Download, DownloadError<C::Error>>
+    where
+        C: Client,
+    {
+        let (image, request) = self.request().ok_or(DownloadError::NoImage)?;
+        let rsp = client.rest(request, Vec::new())?;
+        check_response(image, rsp)
+    }
+
+    /// Download the image using an asynchronous client.
+    pub async fn fetch_async<C>(&self, client: &C) -> Result<Download, DownloadError<C::Error>>
+    where
+        C: AsyncClient + Sync,
+    {
+        let (image, request) = self.request().ok_or(DownloadError::NoImage)?;
+        let rsp = client.rest_async(request, Vec::new()).await?;
+        check_response(image, rsp)
+    }
+}
+
+#[allow(clippy::result_large_err)] // Same error type as the public fetch methods.
+fn check_response<E>(image: &Image, rsp: Response<Bytes>) -> Result<Download, DownloadError<E>>
+where
+    E: Error + Send + Sync + 'static,
+{
+    if !rsp.status().is_success() {
+        return Err(DownloadError::HttpStatus {
+            status: rsp.status(),
+            url: image.url.clone(),
+        });
+    }
+
+    let content_type = rsp
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.trim().to_ascii_lowercase());
+    match content_type {
+        Some(content_type) if format_of(&content_type) == Some(image.format) => Ok(Download {
+            image: image.clone(),
+            content_type,
+            bytes: rsp.into_body(),
+        }),
+        content_type => Err(DownloadError::ContentType {
+            content_type,
+            url: image.url.clone(),
+        }),
+    }
+}
+
+/// Format of an image with `content_type`, ignoring its parameters.
+fn format_of(content_type: &str) -> Option<ImageFormat> {
+    let mime_type = content_type.split(';').next().unwrap_or_default().trim();
+    match mime_type {
+        "image/jpeg" | "image/jpg" | "image/pjpeg" => Some(ImageFormat::Jpg),
+        "image/webp" => Some(ImageFormat::Webp),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::mock::{
+            MockClient,
+            MockRequest,
+        },
+        types::anime::{
+            ImageUrl,
+            PeopleImages,
+        },
+    };
+
+    const URL: &str = "https://cdn.myanimelist.net/images/voiceactors/1/1.jpg";
+
+    fn download() -> ImageDownload {
+        ImageDownload::new(&PeopleImages {
+            jpg: ImageUrl {
+                image_url: Some(URL.parse().unwrap()),
+            },
+        })
+    }
+
+    /// Answers the download of [`URL`].
+    fn mock(status: u16, content_type: Option<&str>) -> MockClient {
+        let mut rsp = Response::builder().status(status);
+        if let Some(content_type) = content_type {
+            rsp = rsp.header(header::CONTENT_TYPE, content_type);
+        }
+        MockClient::new().respond(
+            MockRequest::get(URL),
+            rsp.body(Bytes::from_static(b"not really a jpeg")).unwrap(),
+        )
+    }
+
+    #[test]
+    fn fetch() {
+        let image = download().fetch(&mock(200, Some("image/jpeg"))).unwrap();
+        assert_eq!(image.bytes.as_ref(), b"not really a jpeg");
+        assert_eq!(image.extension(), "jpg");
+
+        let name = image.file_name();
+        assert_eq!(name.len(), 64 + ".jpg".len());
+        assert!(name.ends_with(".jpg"));
+        let again = download()
+            .fetch(&mock(200, Some("IMAGE/JPEG; q=1")))
+            .unwrap();
+        assert_eq!(again.file_name(), name);
+
+        let dir = std::env::temp_dir().join(format!("jikan-download-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = image.save_in(&dir).unwrap();
+        assert_eq!(path, dir.join(&name));
+        assert_eq!(fs::read(&path).unwrap(), b"not really a jpeg");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn fetch_errors() {
+        assert!(matches!(
+            download().fetch(&mock(200, Some("text/html"))),
+            Err(DownloadError::ContentType { content_type: Some(content_type), .. })
+                if content_type == "text/html"
+        ));
+        assert!(matches!(
+            download().fetch(&mock(200, Some("image/png"))),
+            Err(DownloadError::ContentType { content_type: Some(content_type), .. })
+                if content_type == "image/png"
+        ));
+        assert!(matches!(
+            download().fetch(&mock(200, Some("image/x/../../../tmp/evil"))),
+            Err(DownloadError::ContentType { .. })
+        ));
+        assert!(matches!(
+            download().fetch(&mock(200, None)),
+            Err(DownloadError::ContentType {
+                content_type: None,
+                ..
+            })
+        ));
+        assert!(matches!(
+            download().fetch(&mock(404, Some("image/jpeg"))),
+            Err(DownloadError::HttpStatus { status, .. }) if status == StatusCode::NOT_FOUND
+        ));
+        assert!(matches!(
+            ImageDownload::new(Images::default()).fetch(&mock(200, Some("image/jpeg"))),
+            Err(DownloadError::NoImage)
+        ));
+    }
+
+    #[test]
+    fn extension_ignores_the_content_type() {
+        let image = download().fetch(&mock(200, Some("image/jpeg"))).unwrap();
+        let with_type = |content_type: &str| Download {
+            content_type: content_type.to_owned(),
+            ..image.clone()
+        };
+        for content_type in [
+            "image/x/../../../tmp/evil",
+            "image/..",
+            "image/.hidden",
+            "image/a..b",
+            "image/x\\evil",
+            "image/",
+            "text/html",
+        ] {
+            let download = with_type(content_type);
+            assert_eq!(download.extension(), "jpg", "{content_type}");
+            let name = download.file_name();
+            assert!(
+                !name.contains(['/', '\\']) && name.ends_with(".jpg"),
+                "{name}"
+            );
+        }
+    }
+}
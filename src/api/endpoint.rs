@@ -11,9 +11,12 @@ use super::{
     error::BodyError,
     query::{
         AsyncQuery,
+        AsyncRawQuery,
         Query,
+        RawQuery,
     },
     query_params::QueryParams,
+    raw::RawResponse,
     utils,
 };
 
@@ -45,7 +48,7 @@ where
 
         let rsp = client.rest(req, data)?;
 
-        utils::deserialize_response::<_>(rsp)
+        utils::deserialize_response::<_, _>(rsp, client, &url)
             .map(|value| value.data)
             .map_err(|err| ApiError::from_http_response(err, url))
     }
@@ -64,8 +67,43 @@ where
 
         let rsp = client.rest_async(req, data).await?;
 
-        utils::deserialize_response::<_>(rsp)
+        utils::deserialize_response::<_, _>(rsp, client, &url)
             .map(|value| value.data)
             .map_err(|err| ApiError::from_http_response(err, url))
     }
 }
+
+impl<E, C> RawQuery<C> for E
+where
+    E: Endpoint,
+    C: Client,
+{
+    fn raw_query(&self, client: &C) -> Result<RawResponse, ApiError<C::Error>> {
+        let (req, data) = utils::build_request(self, client)?;
+        let url = req.uri_ref().cloned().unwrap_or_default();
+
+        let rsp = client.rest(req, data)?;
+
+        utils::check_response(rsp)
+            .map(|body| RawResponse::new(url.clone(), body))
+            .map_err(|err| ApiError::from_http_response(err, url))
+    }
+}
+
+#[async_trait]
+impl<E, C> AsyncRawQuery<C> for E
+where
+    E: Endpoint + Sync,
+    C: AsyncClient + Sync,
+{
+    async fn raw_query_async(&self, client: &C) -> Result<RawResponse, ApiError<C::Error>> {
+        let (req, data) = utils::build_request(self, client)?;
+        let url = req.uri_ref().cloned().unwrap_or_default();
+
+        let rsp = client.rest_async(req, data).await?;
+
+        utils::check_response(rsp)
+            .map(|body| RawResponse::new(url.clone(), body))
+            .map_err(|err| ApiError::from_http_response(err, url))
+    }
+}
@@ -1,25 +1,70 @@
-use std::error::Error;
+use std::{
+    error::Error,
+    fmt,
+};
 
 use thiserror::Error;
 
 /// Errors from response.
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum ResponseError {
+    /// The body is not valid JSON.
     #[error("Parsing JSON: {0}")]
     Parse(#[from] serde_json::Error),
+    /// The body does not match the expected type.
     #[error("Deserializing value: {source}")]
     DataType {
+        /// Source of the error.
         source: serde_json::Error,
+        /// Body of the response.
         value: serde_json::Value,
+        /// Name of the expected type.
         type_name: &'static str,
     },
+    /// The body has fields the expected type does not know, see
+    /// [`super::DecodeMode::Strict`].
+    #[error("Unknown fields: {}", fmt_fields(fields))]
+    UnknownFields {
+        /// The unknown fields.
+        fields: Vec<UnknownField>,
+        /// Body of the response.
+        value: serde_json::Value,
+    },
+    /// The server answered with an error status.
     #[error("HTTP error: {status}")]
     HttpStatus {
+        /// Body of the response.
         value: serde_json::Value,
+        /// Status of the response.
         status: http::StatusCode,
     },
 }
 
+/// Field of a response the type holding it does not know, see
+/// [`ResponseError::UnknownFields`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownField {
+    /// Path of the field, e.g. `data[3].trailer.images`.
+    pub path: String,
+    /// Name of the type holding the field.
+    pub type_name: &'static str,
+}
+
+impl fmt::Display for UnknownField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` in {}", self.path, self.type_name)
+    }
+}
+
+fn fmt_fields(fields: &[UnknownField]) -> String {
+    fields
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 /// Errors that occur when creating form data.
 #[derive(Debug, Error)]
 #[non_exhaustive]
@@ -27,6 +72,7 @@ pub enum BodyError {
     /// Error serializing body data from form paramaters.
     #[error("URL encode error: {0}")]
     UrlEncoded(#[from] serde_urlencoded::ser::Error),
+    /// Error serializing body data as JSON.
     #[error("JSON encode error: {0}")]
     Json(#[from] serde_json::Error),
 }
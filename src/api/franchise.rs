@@ -0,0 +1,617 @@
+//! Franchise traversal over anime relations.
+//!
+//! [`Franchise`] walks the relations of an anime breadth-first and collects
+//! every related entry into a [`FranchiseGraph`]. Each anime is fetched once
+//! from the `/anime/{id}/full` endpoint, which returns its relations along
+//! with the details needed to order the franchise. Requests are sent one at a
+//! time and are not throttled, so a large franchise can exceed the rate limit
+//! of Jikan; use [`Franchise::max_anime`] or a client which throttles.
+//!
+//! [`WatchOrder`] turns a graph into a suggested viewing order.
+
+use std::collections::{
+    BTreeSet,
+    HashMap,
+    HashSet,
+    VecDeque,
+};
+
+use super::{
+    ApiError,
+    AsyncClient,
+    AsyncQuery,
+    Client,
+    Query,
+    anime::AnimeFull as AnimeFullEndpoint,
+};
+use crate::types::{
+    AnimeId,
+    AnimeType,
+    EntryType,
+    RelationKind,
+    anime::{
+        AnimeFull,
+        MalUrl,
+    },
+};
+
+/// A relation from a fetched anime to another entry.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FranchiseEdge {
+    /// Anime the relation was reported by.
+    pub from: AnimeId,
+    /// Related entry.
+    pub to: MalUrl,
+    /// How `to` relates to `from`.
+    pub kind: RelationKind,
+}
+
+/// Entries of a franchise and the relations between them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FranchiseGraph {
+    seed: AnimeId,
+    entries: Vec<MalUrl>,
+    anime: HashMap<AnimeId, AnimeFull>,
+    edges: Vec<FranchiseEdge>,
+}
+
+impl FranchiseGraph {
+    /// Anime the walk started from.
+    pub fn seed(&self) -> AnimeId {
+        self.seed
+    }
+
+    /// Every entry of the franchise, each listed once, in the order they were
+    /// discovered.
+    ///
+    /// Includes entries which were not fetched, such as manga or anime past
+    /// [`Franchise::max_anime`].
+    pub fn entries(&self) -> &[MalUrl] {
+        &self.entries
+    }
+
+    /// Details of a fetched anime.
+    pub fn anime(&self, id: impl Into<AnimeId>) -> Option<&AnimeFull> {
+        self.anime.get(&id.into())
+    }
+
+    /// Details of every fetched anime, in no particular order.
+    pub fn fetched(&self) -> impl Iterator<Item = &AnimeFull> {
+        self.anime.values()
+    }
+
+    /// Every relation reported by the fetched anime.
+    pub fn edges(&self) -> &[FranchiseEdge] {
+        &self.edges
+    }
+
+    /// Relations reported by the anime `id`.
+    pub fn related(&self, id: impl Into<AnimeId>) -> impl Iterator<Item = &FranchiseEdge> {
+        let id = id.into();
+        self.edges.iter().filter(move |edge| edge.from == id)
+    }
+}
+
+/// Breadth-first walk over the relations of an anime.
+#[derive(Debug, Clone)]
+pub struct Franchise {
+    seed: AnimeId,
+    follow: Option<HashSet<RelationKind>>,
+    max_anime: Option<usize>,
+}
+
+impl Franchise {
+    /// Create a walk starting from the anime `seed`.
+    pub fn new(seed: impl Into<AnimeId>) -> Self {
+        Self {
+            seed: seed.into(),
+            follow: None,
+            max_anime: None,
+        }
+    }
+
+    /// Only fetch anime reached through one of these relations.
+    ///
+    /// By default every relation is followed. Entries reached through other
+    /// relations are still part of the graph, but are not fetched.
+    pub fn follow(mut self, kinds: impl IntoIterator<Item = RelationKind>) -> Self {
+        self.follow = Some(kinds.into_iter().collect());
+        self
+    }
+
+    /// Stop after fetching `max_anime` anime, including the seed.
+    pub fn max_anime(mut self, max_anime: usize) -> Self {
+        self.max_anime = Some(max_anime);
+        self
+    }
+
+    /// Walk the franchise using a blocking client.
+    #[allow(clippy::result_large_err)] // `ApiError` is returned by value across the API.
+    pub fn walk<C>(&self, client: &C) -> Result<FranchiseGraph, ApiError<C::Error>>
+    where
+        C: Client,
+    {
+        let mut walk = Walk::new(self);
+        while let Some(id) = walk.next() {
+            let anime = AnimeFullEndpoint::builder()
+                .id(id)
+                .build()
+                .expect("id is set")
+                .query(client)?;
+            walk.visit(anime);
+        }
+        Ok(walk.finish())
+    }
+
+    /// Walk the franchise using an asynchronous client.
+    pub async fn walk_async<C>(&self, client: &C) -> Result<FranchiseGraph, ApiError<C::Error>>
+    where
+        C: AsyncClient + Sync,
+    {
+        let mut walk = Walk::new(self);
+        while let Some(id) = walk.next() {
+            let endpoint = AnimeFullEndpoint::builder()
+                .id(id)
+                .build()
+                .expect("id is set");
+            walk.visit(endpoint.query_async(client).await?);
+        }
+        Ok(walk.finish())
+    }
+}
+
+/// Suggested viewing order of the anime in a [`FranchiseGraph`].
+///
+/// Prequel and sequel relations are always respected, side stories and
+/// summaries come after their main story, everything else is interleaved by
+/// air date. Only fetched anime are ordered, entries with an unknown air date
+/// come last.
+#[derive(Debug, Clone, Default)]
+pub struct WatchOrder {
+    exclude_types: HashSet<AnimeType>,
+    exclude_relations: HashSet<RelationKind>,
+}
+
+impl WatchOrder {
+    /// Create a watch order including every fetched anime.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Leave out anime of these types, e.g. [`AnimeType::Music`] or
+    /// [`AnimeType::Pv`].
+    pub fn exclude_types(mut self, types: impl IntoIterator<Item = AnimeType>) -> Self {
+        self.exclude_types.extend(types);
+        self
+    }
+
+    /// Leave out anime which are only related to the franchise through these
+    /// relations, e.g. [`RelationKind::Summary`] for recaps.
+    ///
+    /// The seed is never left out.
+    pub fn exclude_relations(mut self, kinds: impl IntoIterator<Item = RelationKind>) -> Self {
+        self.exclude_relations.extend(kinds);
+        self
+    }
+
+    fn includes(&self, graph: &FranchiseGraph, anime: &AnimeFull) -> bool {
+        if anime
+            .anime_type
+            .as_ref()
+            .is_some_and(|ty| self.exclude_types.contains(ty))
+        {
+            return false;
+        }
+        if anime.mal_id == graph.seed || self.exclude_relations.is_empty() {
+            return true;
+        }
+        graph
+            .edges
+            .iter()
+            .filter(|edge| edge.to.url_type == EntryType::Anime && edge.to.mal_id == anime.mal_id.0)
+            .any(|edge| !self.exclude_relations.contains(&edge.kind))
+    }
+
+    /// Orders the fetched anime of `graph`.
+    pub fn build<'a>(&self, graph: &'a FranchiseGraph) -> Vec<&'a AnimeFull> {
+        let anime: HashMap<AnimeId, &AnimeFull> = graph
+            .anime
+            .values()
+            .filter(|anime| self.includes(graph, anime))
+            .map(|anime| (anime.mal_id, anime))
+            .collect();
+
+        // Pairs of `(first, then)`: main stories come before their sequels,
+        // side stories and summaries.
+        let mut constraints = HashSet::new();
+        for edge in &graph.edges {
+            let Ok(to) = AnimeId::try_from(&edge.to) else {
+                continue;
+            };
+            if !anime.contains_key(&edge.from) || !anime.contains_key(&to) {
+                continue;
+            }
+            match edge.kind {
+                RelationKind::Sequel | RelationKind::SideStory | RelationKind::Summary => {
+                    constraints.insert((edge.from, to));
+                }
+                RelationKind::Prequel | RelationKind::ParentStory | RelationKind::FullStory => {
+                    constraints.insert((to, edge.from));
+                }
+                _ => {}
+            }
+        }
+
+        // Anime are sorted by air date, unknown dates last. An anime which has
+        // to be watched before an earlier one is pulled forward to its date.
+        let aired = |id: &AnimeId| {
+            let from = anime[id].aired.dates().from.map(|date| date.first_day());
+            (from.is_none(), from)
+        };
+        let mut priority: HashMap<AnimeId, _> = anime.keys().map(|id| (*id, aired(id))).collect();
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (first, then) in &constraints {
+                if priority[then] < priority[first] {
+                    priority.insert(*first, priority[then]);
+                    changed = true;
+                }
+            }
+        }
+
+        let mut before: HashMap<AnimeId, HashSet<AnimeId>> =
+            anime.keys().map(|id| (*id, HashSet::new())).collect();
+        for (first, then) in &constraints {
+            before.get_mut(then).unwrap().insert(*first);
+        }
+
+        let mut pending: BTreeSet<_> = anime
+            .keys()
+            .map(|id| (priority[id], aired(id), *id))
+            .collect();
+        let mut order = Vec::with_capacity(anime.len());
+        while !pending.is_empty() {
+            // Inconsistent relations can form a cycle, the earliest anime is
+            // taken regardless of its constraints then.
+            let next = pending
+                .iter()
+                .find(|(_, _, id)| before[id].is_empty())
+                .or_else(|| pending.first())
+                .copied()
+                .unwrap();
+            pending.remove(&next);
+
+            let id = next.2;
+            for prequels in before.values_mut() {
+                prequels.remove(&id);
+            }
+            order.push(anime[&id]);
+        }
+        order
+    }
+}
+
+/// State of a walk, shared by the blocking and asynchronous implementations.
+struct Walk<'a> {
+    options: &'a Franchise,
+    queue: VecDeque<AnimeId>,
+    queued: HashSet<AnimeId>,
+    seen: HashSet<(EntryType, u32)>,
+    graph: FranchiseGraph,
+}
+
+impl<'a> Walk<'a> {
+    fn new(options: &'a Franchise) -> Self {
+        Self {
+            options,
+            queue: VecDeque::from([options.seed]),
+            queued: HashSet::from([options.seed]),
+            seen: HashSet::new(),
+            graph: FranchiseGraph {
+                seed: options.seed,
+                entries: Vec::new(),
+                anime: HashMap::new(),
+                edges: Vec::new(),
+            },
+        }
+    }
+
+    fn next(&mut self) -> Option<AnimeId> {
+        if self
+            .options
+            .max_anime
+            .is_some_and(|max| self.graph.anime.len() >= max)
+        {
+            return None;
+        }
+        self.queue.pop_front()
+    }
+
+    fn add_entry(&mut self, entry: &MalUrl) {
+        if self.seen.insert((entry.url_type.clone(), entry.mal_id)) {
+            self.graph.entries.push(entry.clone());
+        }
+    }
+
+    fn visit(&mut self, anime: AnimeFull) {
+        let from = anime.mal_id;
+        self.add_entry(&MalUrl {
+            mal_id: from.0,
+            url_type: EntryType::Anime,
+            name: anime.title.clone(),
+            url: anime.url.clone(),
+        });
+
+        for relation in &anime.relations {
+            let follow = self
+                .options
+                .follow
+                .as_ref()
+                .is_none_or(|kinds| kinds.contains(&relation.relation));
+            for entry in &relation.entry {
+                self.add_entry(entry);
+                self.graph.edges.push(FranchiseEdge {
+                    from,
+                    to: entry.clone(),
+                    kind: relation.relation.clone(),
+                });
+
+                if let Ok(id) = AnimeId::try_from(entry)
+                    && follow
+                    && self.queued.insert(id)
+                {
+                    self.queue.push_back(id);
+                }
+            }
+        }
+        self.graph.anime.insert(from, anime);
+    }
+
+    fn finish(self) -> FranchiseGraph {
+        self.graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{
+        Value,
+        json,
+    };
+
+    use super::*;
+    use crate::api::mock::{
+        MockClient,
+        MockRequest,
+    };
+
+    /// `/anime/{id}/full` response of anime `id` with `relations`.
+    fn anime(id: u32, relations: Value) -> Value {
+        let mut root: Value =
+            serde_json::from_str(include_str!("../../tests/fixtures/anime/anime_full.json"))
+                .unwrap();
+        root["data"]["mal_id"] = json!(id);
+        root["data"]["title"] = json!(format!("Anime {id}"));
+        root["data"]["relations"] = relations;
+        root
+    }
+
+    /// Sets the type and start date of an anime.
+    fn details(mut root: Value, ty: &str, (year, month, day): (u32, u32, u32)) -> Value {
+        let data = &mut root["data"];
+        data["type"] = json!(ty);
+        data["aired"]["prop"]["from"] = json!({ "day": day, "month": month, "year": year });
+        root
+    }
+
+    /// Serves the `/anime/{id}/full` responses of `anime`.
+    fn client(anime: impl IntoIterator<Item = Value>) -> MockClient {
+        anime.into_iter().fold(MockClient::new(), |client, root| {
+            let path = format!("/anime/{}/full", root["data"]["mal_id"]);
+            client.respond_json(MockRequest::get(path), root.to_string())
+        })
+    }
+
+    fn paths(client: &MockClient) -> Vec<String> {
+        client
+            .requests()
+            .iter()
+            .map(|request| request.path().to_owned())
+            .collect()
+    }
+
+    fn relation(kind: &str, entries: &[(&str, u32)]) -> Value {
+        let entries: Vec<_> = entries
+            .iter()
+            .map(|(ty, id)| {
+                json!({
+                    "mal_id": id,
+                    "type": ty,
+                    "name": format!("Entry {id}"),
+                    "url": format!("https://myanimelist.net/{ty}/{id}"),
+                })
+            })
+            .collect();
+        json!({ "relation": kind, "entry": entries })
+    }
+
+    fn mock() -> MockClient {
+        client([
+            anime(
+                1,
+                json!([
+                    relation("Sequel", &[("anime", 2)]),
+                    relation("Adaptation", &[("manga", 173)]),
+                    relation("Side Story", &[("anime", 5)]),
+                ]),
+            ),
+            anime(
+                2,
+                json!([
+                    relation("Prequel", &[("anime", 1)]),
+                    relation("Sequel", &[("anime", 3)]),
+                ]),
+            ),
+            anime(
+                3,
+                json!([
+                    relation("Prequel", &[("anime", 2)]),
+                    relation("Character", &[("anime", 99)]),
+                ]),
+            ),
+            anime(5, json!([relation("Parent Story", &[("anime", 1)])])),
+            anime(99, json!([relation("Character", &[("anime", 3)])])),
+        ])
+    }
+
+    fn ids(entries: &[MalUrl]) -> Vec<(EntryType, u32)> {
+        entries
+            .iter()
+            .map(|entry| (entry.url_type.clone(), entry.mal_id))
+            .collect()
+    }
+
+    #[test]
+    fn walk_breadth_first() {
+        let client = mock();
+        let graph = Franchise::new(1u32).walk(&client).unwrap();
+
+        assert_eq!(paths(&client), [
+            "/anime/1/full",
+            "/anime/2/full",
+            "/anime/5/full",
+            "/anime/3/full",
+            "/anime/99/full",
+        ]);
+        assert_eq!(ids(graph.entries()), [
+            (EntryType::Anime, 1),
+            (EntryType::Anime, 2),
+            (EntryType::Manga, 173),
+            (EntryType::Anime, 5),
+            (EntryType::Anime, 3),
+            (EntryType::Anime, 99),
+        ]);
+        assert_eq!(graph.seed(), AnimeId(1));
+        assert_eq!(graph.anime(3u32).unwrap().title, "Anime 3");
+        assert_eq!(graph.edges().len(), 9);
+        assert_eq!(
+            graph
+                .related(2u32)
+                .map(|edge| &edge.kind)
+                .collect::<Vec<_>>(),
+            [&RelationKind::Prequel, &RelationKind::Sequel]
+        );
+    }
+
+    #[test]
+    fn walk_options() {
+        let client = mock();
+        let graph = Franchise::new(1u32)
+            .follow([RelationKind::Sequel, RelationKind::Prequel])
+            .walk(&client)
+            .unwrap();
+        assert_eq!(paths(&client), [
+            "/anime/1/full",
+            "/anime/2/full",
+            "/anime/3/full",
+        ]);
+        // Entries reached through other relations are listed but not fetched.
+        assert!(graph.entries().iter().any(|entry| entry.mal_id == 99));
+        assert!(graph.anime(99u32).is_none());
+
+        let client = mock();
+        let graph = Franchise::new(1u32).max_anime(2).walk(&client).unwrap();
+        assert_eq!(client.requests().len(), 2);
+        assert_eq!(graph.fetched().count(), 2);
+    }
+
+    fn graph(client: &MockClient) -> FranchiseGraph {
+        Franchise::new(1u32).walk(client).unwrap()
+    }
+
+    fn order(order: &WatchOrder, graph: &FranchiseGraph) -> Vec<u32> {
+        order
+            .build(graph)
+            .iter()
+            .map(|anime| anime.mal_id.0)
+            .collect()
+    }
+
+    #[test]
+    fn watch_order() {
+        let client = client([
+            details(
+                anime(
+                    1,
+                    json!([
+                        relation("Sequel", &[("anime", 2)]),
+                        relation("Prequel", &[("anime", 10)]),
+                        relation("Side Story", &[("anime", 5)]),
+                        relation("Summary", &[("anime", 7)]),
+                        relation("Other", &[("anime", 8)]),
+                        relation("Adaptation", &[("manga", 173)]),
+                    ]),
+                ),
+                "TV",
+                (2009, 7, 3),
+            ),
+            details(
+                anime(2, json!([relation("Prequel", &[("anime", 1)])])),
+                "TV",
+                (2012, 7, 7),
+            ),
+            details(
+                anime(5, json!([relation("Parent Story", &[("anime", 1)])])),
+                "OVA",
+                (2009, 6, 1),
+            ),
+            details(
+                anime(7, json!([relation("Full Story", &[("anime", 1)])])),
+                "Special",
+                (2012, 1, 1),
+            ),
+            details(
+                anime(8, json!([relation("Other", &[("anime", 1)])])),
+                "Music",
+                (2011, 3, 1),
+            ),
+            details(
+                anime(10, json!([relation("Sequel", &[("anime", 1)])])),
+                "Movie",
+                (2016, 1, 8),
+            ),
+        ]);
+        let graph = graph(&client);
+
+        // The prequel movie aired last but comes first, the side story aired
+        // before the main story but comes after it.
+        assert_eq!(order(&WatchOrder::new(), &graph), [10, 1, 5, 8, 7, 2]);
+        assert_eq!(
+            order(
+                &WatchOrder::new()
+                    .exclude_types([AnimeType::Music])
+                    .exclude_relations([RelationKind::Summary]),
+                &graph
+            ),
+            [10, 1, 5, 2]
+        );
+    }
+
+    #[test]
+    fn watch_order_with_cycle() {
+        let client = client([
+            details(
+                anime(1, json!([relation("Sequel", &[("anime", 2)])])),
+                "TV",
+                (2001, 1, 1),
+            ),
+            details(
+                anime(2, json!([relation("Sequel", &[("anime", 1)])])),
+                "TV",
+                (2002, 1, 1),
+            ),
+        ]);
+        assert_eq!(order(&WatchOrder::new(), &graph(&client)), [1, 2]);
+    }
+}
@@ -0,0 +1,333 @@
+//! Client serving canned responses, for testing code using the API without
+//! network access.
+//!
+//! Requests are matched by method, path and query. The path is relative to
+//! the API, e.g. `/anime/1/full`; requests outside of the API such as image
+//! downloads are matched by their full URL instead.
+
+use std::{
+    fmt,
+    sync::Mutex,
+};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::{
+    HeaderMap,
+    HeaderValue,
+    Method,
+    Response,
+    StatusCode,
+    header,
+    request::Builder as RequestBuilder,
+};
+use thiserror::Error;
+use url::Url;
+
+use super::{
+    ApiError,
+    AsyncClient,
+    Client,
+    DecodeMode,
+    DecodeWarning,
+    RestClient,
+    WarningHandler,
+};
+
+/// Errors raised by the [`MockClient`].
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum MockError {
+    /// No response was registered for the request.
+    #[error("no response registered for {0}")]
+    Unexpected(MockRequest),
+    /// The request could not be built.
+    #[error("HTTP error: {0}")]
+    Http(#[from] http::Error),
+}
+
+/// Request as matched by the [`MockClient`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MockRequest {
+    method: Method,
+    path: String,
+    query: Vec<(String, String)>,
+}
+
+impl MockRequest {
+    /// Create a request with `method` to `path`, without query parameters.
+    pub fn new(method: Method, path: impl AsRef<str>) -> Self {
+        Self {
+            method,
+            path: normalize_path(path.as_ref()),
+            query: Vec::new(),
+        }
+    }
+
+    /// Create a `GET` request to `path`.
+    pub fn get(path: impl AsRef<str>) -> Self {
+        Self::new(Method::GET, path)
+    }
+
+    /// Add a query parameter. The order of the parameters does not matter.
+    pub fn query(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.query.push((key.into(), value.into()));
+        self.query.sort();
+        self
+    }
+
+    /// Method of the request.
+    pub fn method(&self) -> &Method {
+        &self.method
+    }
+
+    /// Path of the request, relative to the API.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Query parameters of the request, sorted.
+    pub fn query_pairs(&self) -> &[(String, String)] {
+        &self.query
+    }
+
+    fn from_url(method: Method, url: &Url, rest_url: &Url) -> Self {
+        let path = match url.as_str().strip_prefix(rest_url.as_str()) {
+            Some(_) => &url.path()[rest_url.path().len()..],
+            None => &url[..url::Position::AfterPath],
+        };
+        let mut request = Self::new(method, path);
+        request.query = url.query_pairs().into_owned().collect();
+        request.query.sort();
+        request
+    }
+}
+
+fn normalize_path(path: &str) -> String {
+    if path.contains("://") {
+        path.to_owned()
+    } else {
+        format!("/{}", path.trim_start_matches('/'))
+    }
+}
+
+impl fmt::Display for MockRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.method, self.path)?;
+        for (i, (key, value)) in self.query.iter().enumerate() {
+            let separator = if i == 0 { '?' } else { '&' };
+            write!(f, "{separator}{key}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct MockResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+/// Client answering requests with canned responses.
+///
+/// Each registered response answers every matching request, the first one
+/// registered wins when several match. Requests without a registered
+/// response fail with [`MockError::Unexpected`].
+#[derive(Debug)]
+pub struct MockClient {
+    rest_url: Url,
+    responses: Vec<(MockRequest, MockResponse)>,
+    requests: Mutex<Vec<MockRequest>>,
+    decode_mode: DecodeMode,
+    warnings: WarningHandler,
+}
+
+impl MockClient {
+    /// Create a client without any response.
+    pub fn new() -> Self {
+        Self {
+            rest_url: Url::parse("https://api.jikan.moe/v4/").expect("valid base URL"),
+            responses: Vec::new(),
+            requests: Mutex::default(),
+            decode_mode: DecodeMode::default(),
+            warnings: WarningHandler::default(),
+        }
+    }
+
+    /// Set how responses are deserialized.
+    pub fn with_decode_mode(mut self, mode: DecodeMode) -> Self {
+        self.decode_mode = mode;
+        self
+    }
+
+    /// Answer `request` with `response`.
+    pub fn respond(mut self, request: MockRequest, response: Response<Bytes>) -> Self {
+        let (parts, body) = response.into_parts();
+        self.responses.push((request, MockResponse {
+            status: parts.status,
+            headers: parts.headers,
+            body,
+        }));
+        self
+    }
+
+    /// Answer `request` with a successful JSON response.
+    pub fn respond_json(self, request: MockRequest, body: impl Into<Bytes>) -> Self {
+        let mut response = Response::new(body.into());
+        response.headers_mut().insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+        self.respond(request, response)
+    }
+
+    /// Requests received so far, in order.
+    pub fn requests(&self) -> Vec<MockRequest> {
+        lock(&self.requests).clone()
+    }
+
+    /// Call `handler` with each warning raised while deserializing responses,
+    /// instead of logging it.
+    pub fn with_decode_warning_handler<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&DecodeWarning) + Send + Sync + 'static,
+    {
+        self.warnings = WarningHandler::new(handler);
+        self
+    }
+
+    fn answer(&self, request: RequestBuilder) -> Result<Response<Bytes>, MockError> {
+        let request = request.body(())?;
+        let url = Url::parse(&request.uri().to_string()).map_err(|_| {
+            MockError::Unexpected(MockRequest::new(
+                request.method().clone(),
+                request.uri().to_string(),
+            ))
+        })?;
+        let request = MockRequest::from_url(request.method().clone(), &url, &self.rest_url);
+        lock(&self.requests).push(request.clone());
+
+        let (_, canned) = self
+            .responses
+            .iter()
+            .find(|(expected, _)| *expected == request)
+            .ok_or(MockError::Unexpected(request))?;
+        let mut response = Response::new(canned.body.clone());
+        *response.status_mut() = canned.status;
+        *response.headers_mut() = canned.headers.clone();
+        Ok(response)
+    }
+}
+
+impl Default for MockClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+impl RestClient for MockClient {
+    type Error = MockError;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        Ok(self.rest_url.join(endpoint.trim_start_matches('/'))?)
+    }
+
+    fn decode_mode(&self) -> DecodeMode {
+        self.decode_mode
+    }
+
+    fn decode_warnings(&self, warnings: Vec<DecodeWarning>) {
+        self.warnings.handle(warnings);
+    }
+}
+
+impl Client for MockClient {
+    fn rest(
+        &self,
+        request: RequestBuilder,
+        _body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        self.answer(request).map_err(ApiError::client)
+    }
+}
+
+#[async_trait]
+impl AsyncClient for MockClient {
+    async fn rest_async(
+        &self,
+        request: RequestBuilder,
+        _body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        self.answer(request).map_err(ApiError::client)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{
+        Query,
+        anime::{
+            Forum,
+            ForumFilter,
+        },
+    };
+
+    fn query(client: &MockClient, filter: Option<ForumFilter>) -> Result<(), ApiError<MockError>> {
+        let mut endpoint = Forum::builder();
+        endpoint.id(1u32);
+        if let Some(filter) = filter {
+            endpoint.filter(filter);
+        }
+        let _: serde_json::Value = endpoint.build().unwrap().query(client)?;
+        Ok(())
+    }
+
+    #[test]
+    fn matches_method_path_and_query() {
+        let client = MockClient::new()
+            .respond_json(
+                MockRequest::get("anime/1/forum").query("filter", "episode"),
+                r#"{"data": []}"#,
+            )
+            .respond_json(MockRequest::get("/anime/1/forum"), r#"{"data": []}"#);
+
+        query(&client, Some(ForumFilter::Episode)).unwrap();
+        query(&client, None).unwrap();
+        let err = query(&client, Some(ForumFilter::Other)).unwrap_err();
+        assert!(
+            matches!(&err, ApiError::Client(MockError::Unexpected(request))
+                if request.to_string() == "GET /anime/1/forum?filter=other"),
+            "{err}"
+        );
+
+        let paths: Vec<_> = client.requests().iter().map(|r| r.to_string()).collect();
+        assert_eq!(paths, [
+            "GET /anime/1/forum?filter=episode",
+            "GET /anime/1/forum",
+            "GET /anime/1/forum?filter=other",
+        ]);
+    }
+
+    #[test]
+    fn matches_full_url_outside_of_api() {
+        let url = "https://cdn.myanimelist.net/images/anime/4/19644.jpg";
+        let client = MockClient::new().respond(
+            MockRequest::get(url),
+            Response::builder()
+                .header(header::CONTENT_TYPE, "image/jpeg")
+                .body(Bytes::from_static(b"jpeg"))
+                .unwrap(),
+        );
+        let rsp = client
+            .rest(RequestBuilder::new().uri(url), Vec::new())
+            .unwrap();
+        assert_eq!(rsp.headers()[header::CONTENT_TYPE], "image/jpeg");
+        assert_eq!(rsp.body().as_ref(), b"jpeg");
+    }
+}
@@ -1,4 +1,10 @@
+use std::marker::PhantomData;
+
 use async_trait::async_trait;
+use futures::{
+    StreamExt,
+    stream::BoxStream,
+};
 use log::debug;
 use serde::{
     Serialize,
@@ -10,9 +16,11 @@ use super::{
     AsyncClient,
     AsyncQuery,
     Client,
+    PageCursor,
     Query,
     RestClient,
     endpoint::Endpoint,
+    error::BodyError,
     utils,
 };
 use crate::types::Pagination;
@@ -27,6 +35,25 @@ pub trait PagedEndpointExt<'a, E> {
     where
         C: RestClient,
         T: DeserializeOwned;
+
+    /// Fetch only the first page of the paginated endpoint.
+    ///
+    /// Useful to read the total number of results without walking every page.
+    #[allow(clippy::result_large_err)] // `ApiError` is returned by value across the API.
+    fn first_page<T, C>(&'a self, client: &'a C) -> Result<Page<T>, ApiError<C::Error>>
+    where
+        C: Client,
+        T: DeserializeOwned;
+
+    /// Asynchronously fetch only the first page of the paginated endpoint.
+    fn first_page_async<T, C>(
+        &'a self,
+        client: &'a C,
+    ) -> impl Future<Output = Result<Page<T>, ApiError<C::Error>>> + Send
+    where
+        E: Sync,
+        C: AsyncClient + Sync,
+        T: DeserializeOwned + Send + 'static;
 }
 
 /// Asynchronous iterator over paginated endpoints.
@@ -38,22 +65,218 @@ pub trait AsyncIterator {
     fn next(&mut self) -> impl Future<Output = Option<Self::Item>> + Send;
 }
 
+/// A single page of results from a paginated endpoint.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    /// Items on this page.
+    pub items: Vec<T>,
+    /// Pagination details returned with this page.
+    pub pagination: Pagination,
+    /// Number of this page, starting from 1.
+    pub page_number: u32,
+}
+
+impl<T> Page<T> {
+    /// Total number of items across all pages, if reported by the API.
+    pub fn total(&self) -> Option<u64> {
+        self.pagination.items.as_ref().map(|items| items.total)
+    }
+
+    /// Number of the last page available.
+    pub fn last_visible_page(&self) -> u64 {
+        self.pagination.last_visible_page
+    }
+
+    /// Whether there are more pages after this one.
+    pub fn has_next_page(&self) -> bool {
+        self.pagination.has_next_page
+    }
+}
+
+/// Iterator over the items of a paginated endpoint.
 pub struct PagedIter<'a, E, C, T> {
+    pages: Pages<'a, E, C, T>,
+    current_page: Vec<T>,
+    current_page_number: u32,
+    current_page_len: usize,
+}
+
+/// Iterator over whole pages of a paginated endpoint.
+pub struct Pages<'a, E, C, T> {
     client: &'a C,
     state: InnerState<'a, E>,
-    current_page: Vec<T>,
     last_page: bool,
+    max_pages: Option<u32>,
+    pages_fetched: u32,
+    max_items: Option<usize>,
+    items_fetched: usize,
+    skip_items: usize,
+    last_skipped: usize,
+    _marker: PhantomData<fn() -> T>,
 }
 
+/// Asynchronous iterator over the items of a paginated endpoint which fetches
+/// pages concurrently.
+pub struct ConcurrentPagedIter<'a, E, C, T>
+where
+    C: RestClient,
+{
+    pages: ConcurrentPages<'a, E, C, T>,
+    current_page: Vec<T>,
+    current_page_number: u32,
+    current_page_len: usize,
+}
+
+/// Asynchronous iterator over whole pages of a paginated endpoint which
+/// fetches pages concurrently.
+///
+/// The first page is fetched on its own to discover the number of pages, the
+/// rest are then requested with at most `concurrency` requests in flight and
+/// yielded in order. After an error the failed page is retried on its own
+/// before prefetching resumes.
+///
+/// The clients of this crate do not throttle requests, while Jikan only allows
+/// a few requests per second and answers the rest with `429 Too Many
+/// Requests`. Keep `concurrency` low, or use a client which throttles.
+pub struct ConcurrentPages<'a, E, C, T>
+where
+    C: RestClient,
+{
+    pages: Pages<'a, E, C, T>,
+    concurrency: usize,
+    pending: Option<PageStream<'a, T, C::Error>>,
+}
+
+type PageStream<'a, T, E> = BoxStream<'a, Result<(Vec<T>, Pagination), ApiError<E>>>;
+
 #[derive(Debug, Serialize, Builder)]
 #[builder(pattern = "owned")]
 pub(crate) struct InnerState<'a, E> {
     #[serde(skip)]
     pub(crate) endpoint: &'a E,
     pub(crate) page: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) limit: Option<u32>,
 }
 
 impl<'a, E, C, T> PagedIter<'a, E, C, T>
+where
+    E: Endpoint + Pageable,
+{
+    pub(crate) fn new(endpoint: &'a E, client: &'a C) -> Self {
+        Self {
+            pages: Pages::new(endpoint, client),
+            current_page: Vec::new(),
+            current_page_number: 0,
+            current_page_len: 0,
+        }
+    }
+
+    /// Get a cursor pointing at the next item of this iterator.
+    ///
+    /// The cursor can be persisted and later used to resume the iteration with
+    /// [`PageCursor::resume`].
+    pub fn cursor(&self) -> Result<PageCursor, BodyError> {
+        item_cursor(
+            self.pages.cursor()?,
+            &self.current_page,
+            self.current_page_number,
+            self.current_page_len,
+        )
+    }
+
+    pub(crate) fn skip_items(mut self, count: usize) -> Self {
+        self.pages = self.pages.skip_items(count);
+        self
+    }
+
+    /// Iterate over whole pages instead of individual items.
+    ///
+    /// Items already fetched but not yet yielded by this iterator are
+    /// discarded, iteration continues with the next page.
+    pub fn pages(self) -> Pages<'a, E, C, T> {
+        self.pages
+    }
+
+    /// Start iterating from the given page instead of the first one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `page` is 0, pages are numbered from 1.
+    pub fn start_page(mut self, page: u32) -> Self {
+        self.pages = self.pages.start_page(page);
+        self
+    }
+
+    /// Stop after fetching at most `count` pages.
+    pub fn max_pages(mut self, count: u32) -> Self {
+        self.pages = self.pages.max_pages(count);
+        self
+    }
+
+    /// Stop after yielding at most `count` items.
+    pub fn max_items(mut self, count: usize) -> Self {
+        self.pages = self.pages.max_items(count);
+        self
+    }
+
+    /// Set the number of items requested per page.
+    ///
+    /// Takes precedence over a `limit` set on the endpoint itself.
+    pub fn per_page(mut self, limit: u32) -> Self {
+        self.pages = self.pages.per_page(limit);
+        self
+    }
+}
+
+impl<'a, E, C, T> PagedIter<'a, E, C, T>
+where
+    E: Endpoint + Pageable,
+    C: RestClient,
+{
+    /// Fetch pages concurrently, with at most `concurrency` requests in
+    /// flight.
+    ///
+    /// See [`ConcurrentPages`] for details.
+    pub fn concurrent(self, concurrency: usize) -> ConcurrentPagedIter<'a, E, C, T> {
+        ConcurrentPagedIter {
+            pages: self.pages.concurrent(concurrency),
+            current_page: self.current_page,
+            current_page_number: self.current_page_number,
+            current_page_len: self.current_page_len,
+        }
+    }
+}
+
+impl<'a, E, C, T> ConcurrentPagedIter<'a, E, C, T>
+where
+    E: Endpoint + Pageable,
+    C: RestClient,
+{
+    /// Get a cursor pointing at the next item of this iterator.
+    ///
+    /// The cursor can be persisted and later used to resume the iteration with
+    /// [`PageCursor::resume`].
+    pub fn cursor(&self) -> Result<PageCursor, BodyError> {
+        item_cursor(
+            self.pages.cursor()?,
+            &self.current_page,
+            self.current_page_number,
+            self.current_page_len,
+        )
+    }
+
+    /// Iterate over whole pages instead of individual items.
+    ///
+    /// Items already fetched but not yet yielded by this iterator are
+    /// discarded, iteration continues with the next page.
+    pub fn pages(self) -> ConcurrentPages<'a, E, C, T> {
+        self.pages
+    }
+}
+
+impl<'a, E, C, T> Pages<'a, E, C, T>
 where
     E: Endpoint + Pageable,
 {
@@ -66,9 +289,300 @@ where
         Self {
             client,
             state,
-            current_page: Vec::new(),
             last_page: false,
+            max_pages: None,
+            pages_fetched: 0,
+            max_items: None,
+            items_fetched: 0,
+            skip_items: 0,
+            last_skipped: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Get a cursor pointing at the next page of this iterator.
+    ///
+    /// The cursor can be persisted and later used to resume the iteration with
+    /// [`PageCursor::resume`].
+    pub fn cursor(&self) -> Result<PageCursor, BodyError> {
+        let mut cursor = PageCursor::new(
+            self.state.endpoint,
+            self.state.page,
+            self.skip_items,
+            self.state.limit,
+        )?;
+        cursor.max_pages = self
+            .max_pages
+            .map(|max| max.saturating_sub(self.pages_fetched));
+        cursor.max_items = self
+            .max_items
+            .map(|max| max.saturating_sub(self.items_fetched));
+        Ok(cursor)
+    }
+
+    pub(crate) fn skip_items(mut self, count: usize) -> Self {
+        self.skip_items = count;
+        self
+    }
+
+    /// Start iterating from the given page instead of the first one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `page` is 0, pages are numbered from 1.
+    pub fn start_page(mut self, page: u32) -> Self {
+        assert!(page > 0, "pages are numbered from 1");
+        self.state.page = page;
+        self
+    }
+
+    /// Stop after fetching at most `count` pages.
+    pub fn max_pages(mut self, count: u32) -> Self {
+        self.max_pages = Some(count);
+        self
+    }
+
+    /// Stop after fetching at most `count` items.
+    ///
+    /// The page on which the limit is reached is truncated.
+    pub fn max_items(mut self, count: usize) -> Self {
+        self.max_items = Some(count);
+        self
+    }
+
+    /// Set the number of items requested per page.
+    ///
+    /// Takes precedence over a `limit` set on the endpoint itself.
+    pub fn per_page(mut self, limit: u32) -> Self {
+        self.state.limit = Some(limit);
+        self
+    }
+
+    /// Fetch pages concurrently, with at most `concurrency` requests in
+    /// flight.
+    ///
+    /// See [`ConcurrentPages`] for details.
+    pub fn concurrent(self, concurrency: usize) -> ConcurrentPages<'a, E, C, T>
+    where
+        C: RestClient,
+    {
+        ConcurrentPages {
+            pages: self,
+            concurrency: concurrency.max(1),
+            pending: None,
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.last_page
+            || self.max_pages.is_some_and(|max| self.pages_fetched >= max)
+            || self.max_items.is_some_and(|max| self.items_fetched >= max)
+    }
+
+    fn finish_page<Err>(
+        &mut self,
+        result: Result<(Vec<T>, Pagination), Err>,
+    ) -> Result<Page<T>, Err>
+    where
+        Err: std::fmt::Debug,
+    {
+        match result {
+            Ok((mut items, pagination)) => {
+                let page_number = self.state.page;
+                self.last_page = !pagination.has_next_page;
+                self.state.page += 1;
+                self.pages_fetched += 1;
+                self.last_skipped = self.skip_items.min(items.len());
+                items.drain(..self.last_skipped);
+                self.skip_items = 0;
+                if let Some(max) = self.max_items {
+                    items.truncate(max.saturating_sub(self.items_fetched));
+                }
+                self.items_fetched += items.len();
+                Ok(Page {
+                    items,
+                    pagination,
+                    page_number,
+                })
+            }
+            Err(err) => {
+                debug!("Error in query: {:?}", err);
+                Err(err)
+            }
+        }
+    }
+}
+
+impl<E, C, T> Pages<'_, E, C, T>
+where
+    E: Endpoint + Pageable,
+    T: DeserializeOwned,
+    C: Client,
+{
+    #[allow(clippy::result_large_err)] // `ApiError` is returned by value across the API.
+    fn fetch_page(&mut self) -> Result<Page<T>, ApiError<C::Error>> {
+        debug!("Fetching page {}", self.state.page);
+        let result = self.state.query(self.client);
+        self.finish_page(result)
+    }
+}
+
+impl<E, C, T> Pages<'_, E, C, T>
+where
+    E: Endpoint + Pageable + Sync,
+    T: DeserializeOwned + Send + 'static,
+    C: AsyncClient + Sync,
+{
+    async fn fetch_page_async(&mut self) -> Result<Page<T>, ApiError<C::Error>> {
+        debug!("Fetching page {}", self.state.page);
+        let result = self.state.query_async(self.client).await;
+        self.finish_page(result)
+    }
+}
+
+impl<E, C, T> Iterator for Pages<'_, E, C, T>
+where
+    E: Endpoint + Pageable,
+    T: DeserializeOwned,
+    C: Client,
+{
+    type Item = Result<Page<T>, ApiError<C::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_finished() {
+            return None;
         }
+        Some(self.fetch_page())
+    }
+}
+
+impl<E, C, T> AsyncIterator for Pages<'_, E, C, T>
+where
+    E: Endpoint + Pageable + Sync,
+    T: DeserializeOwned + Send + 'static,
+    C: AsyncClient + Sync,
+{
+    type Item = Result<Page<T>, ApiError<C::Error>>;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        if self.is_finished() {
+            return None;
+        }
+        Some(self.fetch_page_async().await)
+    }
+}
+
+impl<E, C, T> ConcurrentPages<'_, E, C, T>
+where
+    E: Endpoint + Pageable,
+    C: RestClient,
+{
+    /// Get a cursor pointing at the next page of this iterator.
+    ///
+    /// The cursor can be persisted and later used to resume the iteration with
+    /// [`PageCursor::resume`].
+    pub fn cursor(&self) -> Result<PageCursor, BodyError> {
+        self.pages.cursor()
+    }
+}
+
+impl<'a, E, C, T> ConcurrentPages<'a, E, C, T>
+where
+    E: Endpoint + Pageable + Sync,
+    T: DeserializeOwned + Send + 'static,
+    C: AsyncClient + Sync,
+{
+    fn prefetch(&mut self, pagination: &Pagination) {
+        let first = self.pages.state.page;
+        let mut last = u32::try_from(pagination.last_visible_page).unwrap_or(u32::MAX);
+        if let Some(max) = self.pages.max_pages {
+            let remaining = max.saturating_sub(self.pages.pages_fetched);
+            last = last.min(first.saturating_add(remaining).saturating_sub(1));
+        }
+        if let (Some(max), Some(items)) = (self.pages.max_items, &pagination.items) {
+            let remaining = max.saturating_sub(self.pages.items_fetched) as u64;
+            let pages = remaining.div_ceil(items.per_page.max(1));
+            let pages = u32::try_from(pages).unwrap_or(u32::MAX);
+            last = last.min(first.saturating_add(pages).saturating_sub(1));
+        }
+        if first > last {
+            return;
+        }
+
+        let endpoint: &'a E = self.pages.state.endpoint;
+        let client: &'a C = self.pages.client;
+        let limit = self.pages.state.limit;
+        debug!("Prefetching pages {first} to {last}");
+        let stream = futures::stream::iter(first..=last)
+            .map(move |page| async move {
+                let state = InnerState {
+                    endpoint,
+                    page,
+                    limit,
+                };
+                state.query_async(client).await
+            })
+            .buffered(self.concurrency);
+        self.pending = Some(stream.boxed());
+    }
+}
+
+impl<E, C, T> AsyncIterator for ConcurrentPages<'_, E, C, T>
+where
+    E: Endpoint + Pageable + Sync,
+    T: DeserializeOwned + Send + 'static,
+    C: AsyncClient + Sync,
+{
+    type Item = Result<Page<T>, ApiError<C::Error>>;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        if self.pages.is_finished() {
+            self.pending = None;
+            return None;
+        }
+
+        if let Some(pending) = &mut self.pending {
+            if let Some(result) = pending.next().await {
+                let page = self.pages.finish_page(result);
+                if page.is_err() {
+                    self.pending = None;
+                }
+                return Some(page);
+            }
+            self.pending = None;
+        }
+
+        let page = self.pages.fetch_page_async().await;
+        if let Ok(page) = &page
+            && !self.pages.is_finished()
+        {
+            self.prefetch(&page.pagination);
+        }
+        Some(page)
+    }
+}
+
+impl<E, C, T> AsyncIterator for ConcurrentPagedIter<'_, E, C, T>
+where
+    E: Endpoint + Pageable + Sync,
+    T: DeserializeOwned + Send + 'static,
+    C: AsyncClient + Sync,
+{
+    type Item = Result<T, ApiError<C::Error>>;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        if self.current_page.is_empty() {
+            let page = match self.pages.next().await? {
+                Ok(page) => page,
+                Err(err) => return Some(Err(err)),
+            };
+            self.current_page_number = page.page_number;
+            self.current_page_len = self.pages.pages.last_skipped + page.items.len();
+            self.current_page = page.items;
+
+            self.current_page.reverse();
+        }
+        self.current_page.pop().map(Ok)
     }
 }
 
@@ -82,19 +596,13 @@ where
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.current_page.is_empty() {
-            if self.last_page {
-                return None;
-            }
-            debug!("Fetching page {}", self.state.page);
-            (self.current_page, self.last_page) = match self.state.query(self.client) {
-                Ok((data, pagination)) => (data, !pagination.has_next_page),
-                Err(err) => {
-                    debug!("Error in query: {:?}", err);
-                    return Some(Err(err));
-                }
+            let page = match Iterator::next(&mut self.pages)? {
+                Ok(page) => page,
+                Err(err) => return Some(Err(err)),
             };
-
-            self.state.page += 1;
+            self.current_page_number = page.page_number;
+            self.current_page_len = self.pages.last_skipped + page.items.len();
+            self.current_page = page.items;
 
             self.current_page.reverse();
         }
@@ -112,19 +620,13 @@ where
 
     async fn next(&mut self) -> Option<Self::Item> {
         if self.current_page.is_empty() {
-            if self.last_page {
-                return None;
-            }
-            debug!("Fetching page {}", self.state.page);
-            (self.current_page, self.last_page) = match self.state.query_async(self.client).await {
-                Ok((data, pagination)) => (data, !pagination.has_next_page),
-                Err(err) => {
-                    debug!("Error in query: {:?}", err);
-                    return Some(Err(err));
-                }
+            let page = match AsyncIterator::next(&mut self.pages).await? {
+                Ok(page) => page,
+                Err(err) => return Some(Err(err)),
             };
-
-            self.state.page += 1;
+            self.current_page_number = page.page_number;
+            self.current_page_len = self.pages.last_skipped + page.items.len();
+            self.current_page = page.items;
 
             self.current_page.reverse();
         }
@@ -143,6 +645,23 @@ where
     {
         PagedIter::new(self, client)
     }
+
+    fn first_page<T, C>(&'a self, client: &'a C) -> Result<Page<T>, ApiError<C::Error>>
+    where
+        C: Client,
+        T: DeserializeOwned,
+    {
+        Pages::new(self, client).fetch_page()
+    }
+
+    async fn first_page_async<T, C>(&'a self, client: &'a C) -> Result<Page<T>, ApiError<C::Error>>
+    where
+        E: Sync,
+        C: AsyncClient + Sync,
+        T: DeserializeOwned + Send + 'static,
+    {
+        Pages::new(self, client).fetch_page_async().await
+    }
 }
 
 impl<E, T, C> Query<(Vec<T>, Pagination), C> for InnerState<'_, E>
@@ -158,7 +677,7 @@ where
 
         let rsp = client.rest(req, data)?;
 
-        utils::deserialize_response::<_>(rsp)
+        utils::deserialize_response::<_, _>(rsp, client, &url)
             .map(|value| (value.data, value.pagination.unwrap_or_default()))
             .map_err(|err| ApiError::from_http_response(err, url))
     }
@@ -178,8 +697,290 @@ where
 
         let rsp = client.rest_async(req, data).await?;
 
-        utils::deserialize_response::<_>(rsp)
+        utils::deserialize_response::<_, _>(rsp, client, &url)
             .map(|value| (value.data, value.pagination.unwrap_or_default()))
             .map_err(|err| ApiError::from_http_response(err, url))
     }
 }
+
+fn item_cursor<T>(
+    mut cursor: PageCursor,
+    current_page: &[T],
+    current_page_number: u32,
+    current_page_len: usize,
+) -> Result<PageCursor, BodyError> {
+    if !current_page.is_empty() {
+        // The current page is fetched again on resume and its items left
+        // count against the limits again.
+        cursor.page = current_page_number;
+        cursor.skip = current_page_len - current_page.len();
+        cursor.max_pages = cursor.max_pages.map(|max| max + 1);
+        cursor.max_items = cursor.max_items.map(|max| max + current_page.len());
+    }
+    Ok(cursor)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::{
+        io,
+        sync::Mutex,
+    };
+
+    use bytes::Bytes;
+    use http::{
+        Response,
+        request::Builder as RequestBuilder,
+    };
+    use serde_json::json;
+    use url::Url;
+
+    use super::*;
+    use crate::api::anime::News;
+
+    const PER_PAGE: u32 = 3;
+
+    /// Client serving `count` pages of numbered items for any endpoint, as
+    /// many per page as the `limit` parameter asks, [`PER_PAGE`] by default.
+    pub(crate) struct PagesClient {
+        count: u32,
+        requests: Mutex<Vec<Url>>,
+    }
+
+    impl PagesClient {
+        pub(crate) fn new(count: u32) -> Self {
+            Self {
+                count,
+                requests: Mutex::default(),
+            }
+        }
+
+        /// Query strings of the requests received so far, in order.
+        pub(crate) fn queries(&self) -> Vec<String> {
+            let requests = self.requests.lock().unwrap();
+            requests
+                .iter()
+                .map(|url| url.query().unwrap_or_default().to_owned())
+                .collect()
+        }
+
+        /// `page` parameters of the requests received so far, in order.
+        pub(crate) fn pages_requested(&self) -> Vec<String> {
+            let requests = self.requests.lock().unwrap();
+            requests
+                .iter()
+                .flat_map(|url| url.query_pairs())
+                .filter(|(key, _)| key == "page")
+                .map(|(_, page)| page.into_owned())
+                .collect()
+        }
+
+        fn answer(&self, request: RequestBuilder) -> Result<Response<Bytes>, io::Error> {
+            let url = Url::parse(&request.uri_ref().unwrap().to_string()).unwrap();
+            self.requests.lock().unwrap().push(url.clone());
+            let param = |name: &str| {
+                url.query_pairs()
+                    .find(|(key, _)| key == name)
+                    .map(|(_, value)| value.parse::<u32>().unwrap())
+            };
+            let page = param("page").unwrap_or(1);
+            let per_page = param("limit").unwrap_or(PER_PAGE);
+            if page > self.count {
+                return Err(io::Error::other(format!("no page {page}")));
+            }
+
+            let first = (page - 1) * per_page + 1;
+            let items: Vec<_> = (first..first + per_page).collect();
+            let body = json!({
+                "pagination": {
+                    "last_visible_page": self.count,
+                    "has_next_page": page < self.count,
+                    "items": {
+                        "count": per_page,
+                        "total": self.count * per_page,
+                        "per_page": per_page,
+                    },
+                },
+                "data": items,
+            });
+            Ok(Response::new(body.to_string().into()))
+        }
+    }
+
+    impl RestClient for PagesClient {
+        type Error = io::Error;
+
+        fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+            Ok(Url::parse("https://api.jikan.moe/v4/")?.join(endpoint.trim_start_matches('/'))?)
+        }
+    }
+
+    impl Client for PagesClient {
+        fn rest(
+            &self,
+            request: RequestBuilder,
+            _body: Vec<u8>,
+        ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+            self.answer(request).map_err(ApiError::client)
+        }
+    }
+
+    #[async_trait]
+    impl AsyncClient for PagesClient {
+        async fn rest_async(
+            &self,
+            request: RequestBuilder,
+            _body: Vec<u8>,
+        ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+            self.answer(request).map_err(ApiError::client)
+        }
+    }
+
+    fn endpoint() -> News {
+        News::builder().id(1u32).build().unwrap()
+    }
+
+    #[test]
+    fn iterates_over_every_page() {
+        let client = PagesClient::new(3);
+        let endpoint = endpoint();
+        let items: Vec<u32> = endpoint.iter(&client).map(Result::unwrap).collect();
+        assert_eq!(items, (1..=9).collect::<Vec<_>>());
+        assert_eq!(client.pages_requested(), ["1", "2", "3"]);
+    }
+
+    #[test]
+    fn iterates_over_whole_pages() {
+        let client = PagesClient::new(2);
+        let endpoint = endpoint();
+        let pages: Vec<Page<u32>> = endpoint.iter(&client).pages().map(Result::unwrap).collect();
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[1].page_number, 2);
+        assert_eq!(pages[1].items, [4, 5, 6]);
+        assert!(pages[0].has_next_page());
+        assert!(!pages[1].has_next_page());
+    }
+
+    #[test]
+    fn yields_errors() {
+        let client = PagesClient::new(0);
+        let endpoint = endpoint();
+        let mut items = endpoint.iter::<u32, _>(&client);
+        assert!(matches!(
+            Iterator::next(&mut items),
+            Some(Err(ApiError::Client(_)))
+        ));
+    }
+
+    #[test]
+    fn starts_from_page() {
+        let client = PagesClient::new(3);
+        let endpoint = endpoint();
+        let items: Vec<u32> = endpoint
+            .iter(&client)
+            .start_page(2)
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(items, (4..=9).collect::<Vec<_>>());
+        assert_eq!(client.pages_requested(), ["2", "3"]);
+    }
+
+    #[test]
+    #[should_panic = "pages are numbered from 1"]
+    fn rejects_page_zero() {
+        let client = PagesClient::new(1);
+        let endpoint = endpoint();
+        let _ = endpoint.iter::<u32, _>(&client).start_page(0);
+    }
+
+    #[test]
+    fn stops_after_max_pages() {
+        let client = PagesClient::new(3);
+        let endpoint = endpoint();
+        let items: Vec<u32> = endpoint
+            .iter(&client)
+            .max_pages(2)
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(items, (1..=6).collect::<Vec<_>>());
+        assert_eq!(client.pages_requested(), ["1", "2"]);
+    }
+
+    #[test]
+    fn stops_after_max_items() {
+        let client = PagesClient::new(3);
+        let endpoint = endpoint();
+        let pages: Vec<Page<u32>> = endpoint
+            .iter(&client)
+            .pages()
+            .max_items(5)
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(pages[0].items, [1, 2, 3]);
+        assert_eq!(pages[1].items, [4, 5]);
+        assert_eq!(pages.len(), 2);
+        assert_eq!(client.pages_requested(), ["1", "2"]);
+    }
+
+    #[test]
+    fn requests_per_page() {
+        let client = PagesClient::new(1);
+        let endpoint = endpoint();
+        let items: Vec<u32> = endpoint
+            .iter(&client)
+            .per_page(2)
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(items, [1, 2]);
+    }
+
+    #[tokio::test]
+    async fn prefetches_pages_in_order() {
+        let client = PagesClient::new(4);
+        let endpoint = endpoint();
+        let mut iter = endpoint.iter::<u32, _>(&client).concurrent(2);
+        let mut items = Vec::new();
+        while let Some(item) = iter.next().await {
+            items.push(item.unwrap());
+        }
+        assert_eq!(items, (1..=12).collect::<Vec<_>>());
+        assert_eq!(client.pages_requested(), ["1", "2", "3", "4"]);
+    }
+
+    #[tokio::test]
+    async fn prefetches_within_limits() {
+        let client = PagesClient::new(4);
+        let endpoint = endpoint();
+        let mut pages = endpoint
+            .iter::<u32, _>(&client)
+            .max_items(5)
+            .pages()
+            .concurrent(4);
+        let mut items = Vec::new();
+        while let Some(page) = pages.next().await {
+            items.extend(page.unwrap().items);
+        }
+        assert_eq!(items, [1, 2, 3, 4, 5]);
+        assert_eq!(client.pages_requested(), ["1", "2"]);
+    }
+
+    #[test]
+    fn first_page() {
+        let client = PagesClient::new(3);
+        let endpoint = endpoint();
+        let page = endpoint.first_page::<u32, _>(&client).unwrap();
+        assert_eq!(page.items, [1, 2, 3]);
+        assert_eq!(page.total(), Some(9));
+        assert_eq!(page.last_visible_page(), 3);
+        assert_eq!(client.pages_requested(), ["1"]);
+    }
+
+    #[tokio::test]
+    async fn first_page_async() {
+        let client = PagesClient::new(3);
+        let endpoint = endpoint();
+        let page = endpoint.first_page_async::<u32, _>(&client).await.unwrap();
+        assert_eq!(page.items, [1, 2, 3]);
+        assert_eq!(client.pages_requested(), ["1"]);
+    }
+}
@@ -4,6 +4,7 @@ use super::{
     ApiError,
     AsyncClient,
     Client,
+    RawResponse,
 };
 
 /// Query made to a client.
@@ -24,3 +25,27 @@ where
     /// Perform an asynchronous query against the client.
     async fn query_async(&self, client: &C) -> Result<T, ApiError<C::Error>>;
 }
+
+/// Query made to a client, keeping the response body for borrowed
+/// deserialization.
+pub trait RawQuery<C>
+where
+    C: Client,
+{
+    /// Perform a query against the client, without deserializing the
+    /// response.
+    #[allow(clippy::result_large_err)] // Same error type as `Query::query`.
+    fn raw_query(&self, client: &C) -> Result<RawResponse, ApiError<C::Error>>;
+}
+
+/// Asynchronous query made to a client, keeping the response body for
+/// borrowed deserialization.
+#[async_trait]
+pub trait AsyncRawQuery<C>
+where
+    C: AsyncClient,
+{
+    /// Perform an asynchronous query against the client, without
+    /// deserializing the response.
+    async fn raw_query_async(&self, client: &C) -> Result<RawResponse, ApiError<C::Error>>;
+}
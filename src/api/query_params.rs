@@ -1,6 +1,12 @@
-use std::borrow::Borrow;
+use std::{
+    borrow::Borrow,
+    fmt::Display,
+};
 
-use serde::Serialize;
+use serde::{
+    Serialize,
+    Serializer,
+};
 use url::Url;
 
 use super::error::BodyError;
@@ -28,7 +34,6 @@ impl QueryParams<'_> {
         self
     }
 
-    #[allow(dead_code)]
     pub(crate) fn extend_pairs<I, K, V>(&mut self, iter: I) -> &mut Self
     where
         I: IntoIterator,
@@ -45,7 +50,22 @@ impl QueryParams<'_> {
         Ok(self)
     }
 
-    #[allow(dead_code)]
+    /// Serialize `value` on top of the current parameters, replacing any
+    /// existing pairs with the same keys.
+    pub(crate) fn override_with(self, value: &impl Serialize) -> Result<Self, BodyError> {
+        let overrides = Self::with(value)?.finish();
+        let overrides: Vec<_> = form_urlencoded::parse(overrides.as_bytes()).collect();
+        let base = self.finish();
+
+        let mut out = Self::new();
+        out.extend_pairs(
+            form_urlencoded::parse(base.as_bytes())
+                .filter(|(key, _)| !overrides.iter().any(|(other, _)| other == key)),
+        );
+        out.extend_pairs(overrides);
+        Ok(out)
+    }
+
     pub(crate) fn with(value: &impl Serialize) -> Result<Self, BodyError> {
         let mut out = Self::new();
         out.extend_from(value)?;
@@ -75,3 +95,27 @@ impl Default for QueryParams<'_> {
         Self::new()
     }
 }
+
+/// Serialize an optional list of values as a single comma-separated value.
+///
+/// An empty list is skipped like a missing one, as Jikan rejects empty values.
+pub(crate) fn comma_separated<S, T>(
+    values: &Option<Vec<T>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Display,
+{
+    match values {
+        Some(values) if !values.is_empty() => {
+            let joined = values
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(",");
+            serializer.serialize_str(&joined)
+        }
+        _ => serializer.serialize_none(),
+    }
+}
@@ -0,0 +1,52 @@
+use bytes::Bytes;
+use serde::Deserialize;
+
+use super::Root;
+
+/// Response of a [`RawQuery`](super::RawQuery), kept as received.
+///
+/// The response is only deserialized on demand, and the result may borrow
+/// from the retained body, e.g. with the types of
+/// [`crate::types::borrowed`].
+#[derive(Debug, Clone)]
+pub struct RawResponse {
+    url: http::Uri,
+    body: Bytes,
+}
+
+impl RawResponse {
+    pub(crate) fn new(url: http::Uri, body: Bytes) -> Self {
+        Self { url, body }
+    }
+
+    /// URL the response was received from.
+    pub fn url(&self) -> &http::Uri {
+        &self.url
+    }
+
+    /// Body of the response.
+    pub fn body(&self) -> &Bytes {
+        &self.body
+    }
+
+    /// Consume the response and return its body.
+    pub fn into_body(self) -> Bytes {
+        self.body
+    }
+
+    /// Deserialize the whole response, borrowing from the body.
+    pub fn root<'a, T>(&'a self) -> Result<Root<T>, serde_json::Error>
+    where
+        T: Deserialize<'a>,
+    {
+        serde_json::from_slice(&self.body)
+    }
+
+    /// Deserialize the data of the response, borrowing from the body.
+    pub fn data<'a, T>(&'a self) -> Result<T, serde_json::Error>
+    where
+        T: Deserialize<'a>,
+    {
+        self.root().map(|root| root.data)
+    }
+}
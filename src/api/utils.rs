@@ -9,8 +9,15 @@ use super::{
     ApiError,
     RestClient,
     Root,
+    decode::{
+        self,
+        DecodeError,
+    },
     endpoint::Endpoint,
-    error::ResponseError,
+    error::{
+        ResponseError,
+        UnknownField,
+    },
     page::{
         InnerState,
         Pageable,
@@ -31,7 +38,9 @@ where
     E: Endpoint,
     C: RestClient,
 {
-    let url = client.rest_endpoint(&endpoint.endpoint())?;
+    let mut url = client.rest_endpoint(&endpoint.endpoint())?;
+    endpoint.query_params()?.apply_to(&mut url);
+
     build_request_internal(url, endpoint, client)
 }
 
@@ -44,15 +53,18 @@ where
     C: RestClient,
 {
     let mut url = client.rest_endpoint(&state.endpoint.endpoint())?;
-    let mut params = state.endpoint.query_params()?;
-    params.extend_from(&state)?;
-    params.apply_to(&mut url);
+    state
+        .endpoint
+        .query_params()?
+        .override_with(state)?
+        .apply_to(&mut url);
 
     build_request_internal(url, state.endpoint, client)
 }
 
+#[allow(clippy::result_large_err)] // Same error type as the public query methods.
 pub(crate) fn build_request_internal<E, C>(
-    mut url: url::Url,
+    url: url::Url,
     endpoint: &E,
     _client: &C,
 ) -> Result<(RequestBuilder, Vec<u8>), ApiError<C::Error>>
@@ -60,8 +72,6 @@ where
     E: Endpoint,
     C: RestClient,
 {
-    endpoint.query_params()?.apply_to(&mut url);
-
     let req = RequestBuilder::new()
         .method(endpoint.method())
         .uri(url_to_http_uri(url));
@@ -73,9 +83,14 @@ where
     }
 }
 
-pub(crate) fn deserialize_response<T>(rsp: http::Response<Bytes>) -> Result<Root<T>, ResponseError>
+pub(crate) fn deserialize_response<T, C>(
+    rsp: http::Response<Bytes>,
+    client: &C,
+    url: &http::Uri,
+) -> Result<Root<T>, ResponseError>
 where
     T: DeserializeOwned,
+    C: RestClient,
 {
     let status = rsp.status();
     let value = serde_json::from_slice(rsp.body())?;
@@ -83,9 +98,87 @@ where
         return Err(ResponseError::HttpStatus { value, status });
     }
 
-    serde_json::from_value::<Root<T>>(value.clone()).map_err(|err| ResponseError::DataType {
-        source: err,
-        value,
-        type_name: std::any::type_name::<T>(),
-    })
+    let type_name = std::any::type_name::<T>();
+    let (root, warnings) = match decode::decode(&value, client.decode_mode(), url) {
+        Ok(decoded) => decoded,
+        Err(DecodeError::Data(source)) => {
+            return Err(ResponseError::DataType {
+                source,
+                value,
+                type_name,
+            });
+        }
+        Err(DecodeError::UnknownFields(fields)) => {
+            // Fields of maps rather than structs are reported against the
+            // expected type.
+            let fields = fields
+                .into_iter()
+                .map(|(path, owner)| UnknownField {
+                    path,
+                    type_name: owner.unwrap_or(type_name),
+                })
+                .collect();
+            return Err(ResponseError::UnknownFields { fields, value });
+        }
+    };
+    if !warnings.is_empty() {
+        client.decode_warnings(warnings);
+    }
+    Ok(root)
+}
+
+/// Returns the body of a successful response.
+pub(crate) fn check_response(rsp: http::Response<Bytes>) -> Result<Bytes, ResponseError> {
+    let status = rsp.status();
+    if !status.is_success() {
+        let value = serde_json::from_slice(rsp.body())?;
+        return Err(ResponseError::HttpStatus { value, status });
+    }
+
+    Ok(rsp.into_body())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        JikanApiClient,
+        api::anime::AnimeSearch,
+    };
+
+    fn query(req: &RequestBuilder) -> String {
+        req.uri_ref()
+            .unwrap()
+            .query()
+            .unwrap_or_default()
+            .to_owned()
+    }
+
+    #[test]
+    fn sends_endpoint_parameters_once() {
+        let endpoint = AnimeSearch::builder()
+            .query("bebop")
+            .limit(5u32)
+            .build()
+            .unwrap();
+        let (req, _) = build_request(&endpoint, &JikanApiClient::new()).unwrap();
+        assert_eq!(query(&req), "limit=5&q=bebop");
+    }
+
+    #[test]
+    fn paging_parameters_override_endpoint_ones() {
+        let endpoint = AnimeSearch::builder()
+            .query("bebop")
+            .page(5u32)
+            .limit(5u32)
+            .build()
+            .unwrap();
+        let state = InnerState {
+            endpoint: &endpoint,
+            page: 2,
+            limit: Some(10),
+        };
+        let (req, _) = build_paged_request(&state, &JikanApiClient::new()).unwrap();
+        assert_eq!(query(&req), "q=bebop&page=2&limit=10");
+    }
 }
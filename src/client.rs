@@ -10,7 +10,12 @@ use reqwest::{
 use url::Url;
 
 use crate::{
-    api,
+    api::{
+        self,
+        DecodeMode,
+        DecodeWarning,
+        WarningHandler,
+    },
     error::RestError,
 };
 
@@ -21,6 +26,8 @@ const API_BASE_URL: &str = "https://api.jikan.moe/v4/";
 pub struct JikanApiClient {
     client: HttpClient,
     rest_url: Url,
+    decode_mode: DecodeMode,
+    warnings: WarningHandler,
 }
 
 impl JikanApiClient {
@@ -31,8 +38,29 @@ impl JikanApiClient {
         Self {
             client: HttpClient::new(),
             rest_url,
+            decode_mode: DecodeMode::default(),
+            warnings: WarningHandler::default(),
         }
     }
+
+    /// Set how responses are deserialized.
+    ///
+    /// Warnings raised in [`DecodeMode::Lenient`] are logged, see
+    /// [`JikanApiClient::with_decode_warning_handler`].
+    pub fn with_decode_mode(mut self, mode: DecodeMode) -> Self {
+        self.decode_mode = mode;
+        self
+    }
+
+    /// Call `handler` with each warning raised while deserializing responses,
+    /// instead of logging it.
+    pub fn with_decode_warning_handler<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&DecodeWarning) + Send + Sync + 'static,
+    {
+        self.warnings = WarningHandler::new(handler);
+        self
+    }
 }
 
 impl Default for JikanApiClient {
@@ -50,6 +78,14 @@ impl api::RestClient for JikanApiClient {
             .join(endpoint.trim_start_matches('/'))
             .map_err(From::from)
     }
+
+    fn decode_mode(&self) -> DecodeMode {
+        self.decode_mode
+    }
+
+    fn decode_warnings(&self, warnings: Vec<DecodeWarning>) {
+        self.warnings.handle(warnings);
+    }
 }
 
 impl api::Client for JikanApiClient {
@@ -81,6 +117,8 @@ impl api::Client for JikanApiClient {
 pub struct JikanApiClientAsync {
     client: AsyncHttpClient,
     rest_url: Url,
+    decode_mode: DecodeMode,
+    warnings: WarningHandler,
 }
 
 impl JikanApiClientAsync {
@@ -89,7 +127,31 @@ impl JikanApiClientAsync {
         let rest_url =
             Url::parse(API_BASE_URL).expect("Unable to parse API_BASE_URL into url::Url");
         let client = AsyncHttpClient::new();
-        Self { client, rest_url }
+        Self {
+            client,
+            rest_url,
+            decode_mode: DecodeMode::default(),
+            warnings: WarningHandler::default(),
+        }
+    }
+
+    /// Set how responses are deserialized.
+    ///
+    /// Warnings raised in [`DecodeMode::Lenient`] are logged, see
+    /// [`JikanApiClientAsync::with_decode_warning_handler`].
+    pub fn with_decode_mode(mut self, mode: DecodeMode) -> Self {
+        self.decode_mode = mode;
+        self
+    }
+
+    /// Call `handler` with each warning raised while deserializing responses,
+    /// instead of logging it.
+    pub fn with_decode_warning_handler<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&DecodeWarning) + Send + Sync + 'static,
+    {
+        self.warnings = WarningHandler::new(handler);
+        self
     }
 }
 
@@ -108,6 +170,14 @@ impl api::RestClient for JikanApiClientAsync {
             .join(endpoint.trim_start_matches('/'))
             .map_err(From::from)
     }
+
+    fn decode_mode(&self) -> DecodeMode {
+        self.decode_mode
+    }
+
+    fn decode_warnings(&self, warnings: Vec<DecodeWarning>) {
+        self.warnings.handle(warnings);
+    }
 }
 
 #[async_trait]
@@ -1,14 +1,56 @@
 //! Helper types for jikan.moe responses.
 
 pub mod anime;
+pub mod borrowed;
+mod broadcast;
 mod common;
+mod date;
+mod duration;
+mod ids;
+mod images;
+mod theme;
+mod timestamp;
 
+pub use broadcast::{
+    BroadcastError,
+    BroadcastSchedule,
+};
 pub use common::{
     AnimeRating,
     AnimeSeason,
     AnimeStatus,
     AnimeType,
+    EntryType,
     Pagination,
     PaginationItems,
+    RelationKind,
     Root,
 };
+pub use date::{
+    DateError,
+    PartialDate,
+    PartialDateRange,
+};
+pub use duration::RunningTime;
+pub use ids::{
+    AnimeId,
+    CharacterId,
+    EpisodeNumber,
+    GenreId,
+    MangaId,
+    ParseIdError,
+    PersonId,
+    ProducerId,
+    RecommendationPairId,
+};
+pub use images::{
+    Image,
+    ImageFormat,
+    ImageSize,
+    Images,
+};
+pub use theme::{
+    EpisodeRange,
+    ThemeSong,
+};
+pub use timestamp::ApiTimestamp;
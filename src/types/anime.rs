@@ -1,22 +1,49 @@
-use iso8601_timestamp::Timestamp;
+use std::{
+    fmt,
+    ops::{
+        Deref,
+        DerefMut,
+    },
+};
+
 use serde::{
     Deserialize,
+    Deserializer,
     Serialize,
+    de::{
+        self,
+        DeserializeSeed,
+        IntoDeserializer,
+        MapAccess,
+        Visitor,
+        value::MapAccessDeserializer,
+    },
 };
+use url::Url;
 
 use super::{
+    AnimeId,
     AnimeRating,
     AnimeSeason,
     AnimeStatus,
     AnimeType,
+    ApiTimestamp,
+    CharacterId,
+    EntryType,
+    EpisodeNumber,
+    PersonId,
+    RecommendationPairId,
+    RelationKind,
+    RunningTime,
+    ThemeSong,
 };
 
 /// Represents an anime resource.
 /// Root type for the `/anime/{id}` endpoint.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Anime {
     /// MyAnimeList ID.
-    pub mal_id: u32,
+    pub mal_id: AnimeId,
 
     /// MyAnimeList URL.
     pub url: String,
@@ -74,7 +101,7 @@ pub struct Anime {
 
     /// Duration of each episode (if available).
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub duration: Option<String>,
+    pub duration: Option<RunningTime>,
 
     /// Audience rating of the anime (e.g., "G - All Ages", "PG - Children",
     /// etc.).
@@ -156,160 +183,180 @@ pub struct Anime {
 
 /// Represents a complete anime resource with additional details.
 /// Root type for the `/anime/{id}/full` endpoint.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Debug, Clone, PartialEq)]
 pub struct AnimeFull {
-    /// MyAnimeList ID.
-    pub mal_id: u32,
-
-    /// MyAnimeList URL.
-    pub url: String,
-
-    /// Images associated with the anime.
-    pub images: AnimeImages,
-
-    /// Trailer information.
-    pub trailer: TrailerBase,
-
-    /// Whether the entry is pending approval on MyAnimeList.
-    pub approved: bool,
-
-    /// All titles associated with the anime.
-    pub titles: Vec<Title>,
-
-    /// Main title of the anime.
-    pub title: String,
-
-    /// English title of the anime (if available).
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub title_english: Option<String>,
-
-    /// Japanese title of the anime (if available).
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub title_japanese: Option<String>,
-
-    /// Other titles or synonyms for the anime.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub title_synonyms: Vec<String>,
-
-    /// Type of the anime (e.g., TV, Movie, OVA, etc.).
-    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
-    pub anime_type: Option<AnimeType>,
-
-    /// Source material the anime is adapted from (e.g., manga, light novel,
-    /// etc.).
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub source: Option<String>,
+    /// Fields shared with [`Anime`].
+    #[serde(flatten)]
+    pub base: Anime,
 
-    /// Number of episodes in the anime (if available).
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub episodes: Option<u32>,
-
-    /// Airing status of the anime (e.g., "Finished Airing", "Currently Airing",
-    /// etc.).
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<AnimeStatus>,
-
-    /// Whether the anime is currently airing.
-    pub airing: bool,
-
-    /// Date range during which the anime aired.
-    pub aired: DateRange,
-
-    /// Duration of each episode (if available).
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub duration: Option<String>,
-
-    /// Audience rating of the anime (e.g., "G - All Ages", "PG - Children",
-    /// etc.).
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub rating: Option<AnimeRating>,
-
-    /// Average score of the anime (1.00 - 10.00).
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub score: Option<f32>,
-
-    /// Number of users who scored the anime.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub scored_by: Option<u32>,
+    /// List of related anime.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub relations: Vec<AnimeRelation>,
 
-    /// Ranking of the anime based on popularity.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub rank: Option<u32>,
+    /// Opening and ending themes of the anime.
+    pub theme: Theme,
 
-    /// Popularity rank of the anime.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub popularity: Option<u32>,
+    /// External links related to the anime.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub external: Vec<ExternalLink>,
 
-    /// Number of users who have added the anime to their list.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub members: Option<u32>,
+    /// Streaming links for the anime.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub streaming: Vec<ExternalLink>,
+}
 
-    /// Number of users who have favorited the anime.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub favorites: Option<u32>,
+/// Written by hand rather than with `#[serde(flatten)]`, which buffers the
+/// fields of [`Anime`] and hides them from the response decoder: the fields
+/// of [`AnimeFull`] are picked out of the map and the remaining ones are
+/// handed to the `Deserialize` impl of [`Anime`].
+impl<'de> Deserialize<'de> for AnimeFull {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["relations", "theme", "external", "streaming"];
+        deserializer.deserialize_struct("AnimeFull", FIELDS, AnimeFullVisitor)
+    }
+}
 
-    /// Synopsis of the anime.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub synopsis: Option<String>,
+struct AnimeFullVisitor;
+
+impl<'de> Visitor<'de> for AnimeFullVisitor {
+    type Value = AnimeFull;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("struct AnimeFull")
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut map = AnimeFullMap {
+            map,
+            relations: None,
+            theme: None,
+            external: None,
+            streaming: None,
+        };
+        let base = Anime::deserialize(MapAccessDeserializer::new(&mut map))?;
+        Ok(AnimeFull {
+            base,
+            relations: map.relations.unwrap_or_default(),
+            theme: map.theme.ok_or_else(|| de::Error::missing_field("theme"))?,
+            external: map.external.unwrap_or_default(),
+            streaming: map.streaming.unwrap_or_default(),
+        })
+    }
+}
 
-    /// Background information about the anime.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub background: Option<String>,
+/// Map of an [`AnimeFull`] keeping its own fields and passing the others on.
+struct AnimeFullMap<A> {
+    map: A,
+    relations: Option<Vec<AnimeRelation>>,
+    theme: Option<Theme>,
+    external: Option<Vec<ExternalLink>>,
+    streaming: Option<Vec<ExternalLink>>,
+}
 
-    /// Season in which the anime aired (e.g., "summer", "winter", etc.).
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub season: Option<AnimeSeason>,
+impl<'de, A> MapAccess<'de> for AnimeFullMap<A>
+where
+    A: MapAccess<'de>,
+{
+    type Error = A::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        while let Some(key) = self.map.next_key::<String>()? {
+            match key.as_str() {
+                "relations" => self.relations = Some(self.map.next_value()?),
+                "theme" => self.theme = Some(self.map.next_value()?),
+                "external" => self.external = Some(self.map.next_value()?),
+                "streaming" => self.streaming = Some(self.map.next_value()?),
+                _ => return seed.deserialize(key.into_deserializer()).map(Some),
+            }
+        }
+        Ok(None)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        self.map.next_value_seed(seed)
+    }
+}
 
-    /// Year in which the anime aired.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub year: Option<u32>,
+impl Deref for AnimeFull {
+    type Target = Anime;
 
-    /// Broadcast details of the anime.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub broadcast: Option<Broadcast>,
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
 
-    /// List of producers involved in the anime.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub producers: Vec<MalUrl>,
+impl DerefMut for AnimeFull {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.base
+    }
+}
 
-    /// List of licensors for the anime.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub licensors: Vec<MalUrl>,
+impl From<AnimeFull> for Anime {
+    fn from(anime: AnimeFull) -> Self {
+        anime.base
+    }
+}
 
-    /// List of studios that produced the anime.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub studios: Vec<MalUrl>,
+impl AsRef<Anime> for AnimeFull {
+    fn as_ref(&self) -> &Anime {
+        &self.base
+    }
+}
 
-    /// List of genres associated with the anime.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub genres: Vec<MalUrl>,
+/// Fields common to [`Anime`] and [`AnimeFull`], for code working with
+/// either of them.
+pub trait AnimeFields {
+    /// Fields shared by every anime resource.
+    fn base(&self) -> &Anime;
 
-    /// List of explicit genres associated with the anime.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub explicit_genres: Vec<MalUrl>,
+    /// MyAnimeList ID.
+    fn mal_id(&self) -> AnimeId {
+        self.base().mal_id
+    }
 
-    /// List of themes associated with the anime.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub themes: Vec<MalUrl>,
+    /// Main title of the anime.
+    fn title(&self) -> &str {
+        &self.base().title
+    }
 
-    /// List of demographics associated with the anime.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub demographics: Vec<MalUrl>,
+    /// Type of the anime (e.g., TV, Movie, OVA, etc.).
+    fn anime_type(&self) -> Option<&AnimeType> {
+        self.base().anime_type.as_ref()
+    }
 
-    /// List of related anime.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub relations: AnimeRelations,
+    /// Date range during which the anime aired.
+    fn aired(&self) -> &DateRange {
+        &self.base().aired
+    }
 
-    /// Opening and ending themes of the anime.
-    pub theme: Theme,
+    /// Images associated with the anime.
+    fn images(&self) -> &AnimeImages {
+        &self.base().images
+    }
+}
 
-    /// External links related to the anime.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub external: AnimeExternal,
+impl AnimeFields for Anime {
+    fn base(&self) -> &Anime {
+        self
+    }
+}
 
-    /// Streaming links for the anime.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub streaming: AnimeStreaming,
+impl AnimeFields for AnimeFull {
+    fn base(&self) -> &Anime {
+        &self.base
+    }
 }
 
 /// Represents a list of characters in an anime.
@@ -317,22 +364,25 @@ pub struct AnimeFull {
 pub type AnimeCharacters = Vec<AnimeCharacter>;
 
 /// Represents a character in an anime.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct AnimeCharacter {
     /// Metadata about the character.
     pub character: CharacterMeta,
     /// Role of the character in the anime.
     pub role: String,
+    /// Number of users who have favorited the character (if available).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub favorites: Option<u32>,
     /// List of voice actors who voiced the character.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub voice_actors: Vec<VoiceActor>,
 }
 
 /// Represents metadata about a character.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct CharacterMeta {
     /// MyAnimeList ID of the character.
-    pub mal_id: u32,
+    pub mal_id: CharacterId,
     /// MyAnimeList URL of the character.
     pub url: String,
     /// Images of the character.
@@ -342,7 +392,7 @@ pub struct CharacterMeta {
 }
 
 /// Represents a voice actor for a character.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct VoiceActor {
     /// Metadata about the voice actor.
     pub person: PersonMeta,
@@ -351,10 +401,10 @@ pub struct VoiceActor {
 }
 
 /// Represents metadata about a person (e.g., voice actor, staff member).
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct PersonMeta {
     /// MyAnimeList ID of the person.
-    pub mal_id: u32,
+    pub mal_id: PersonId,
     /// MyAnimeList URL of the person.
     pub url: String,
     /// Images of the person.
@@ -368,7 +418,7 @@ pub struct PersonMeta {
 pub type AnimeStaff = Vec<StaffRole>;
 
 /// Represents a staff member's role in an anime.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct StaffRole {
     /// Metadata about the staff member.
     pub person: PersonMeta,
@@ -382,10 +432,10 @@ pub struct StaffRole {
 pub type AnimeEpisodes = Vec<AnimeEpisode>;
 
 /// Represents an episode in an anime.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct AnimeEpisode {
-    /// MyAnimeList ID of the episode.
-    pub mal_id: u32,
+    /// Number of the episode.
+    pub mal_id: EpisodeNumber,
     /// MyAnimeList URL of the episode.
     pub url: String,
     /// Title of the episode.
@@ -401,7 +451,10 @@ pub struct AnimeEpisode {
     pub duration: Option<u32>,
     /// Date the episode aired (if available).
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub aired: Option<String>,
+    pub aired: Option<ApiTimestamp>,
+    /// Average score of the episode (1.00 - 5.00, if available).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<f32>,
     /// Whether the episode is a filler.
     pub filler: bool,
     /// Whether the episode is a recap.
@@ -409,6 +462,10 @@ pub struct AnimeEpisode {
     /// MyAnimeList forum URL for the episode (if available).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub forum_url: Option<String>,
+    /// Synopsis of the episode, only sent by the
+    /// `/anime/{id}/episodes/{episode}` endpoint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub synopsis: Option<String>,
 }
 
 /// Represents a list of news articles related to an anime.
@@ -416,7 +473,7 @@ pub struct AnimeEpisode {
 pub type AnimeNews = Vec<AnimeNewsMeta>;
 
 /// Represents metadata about a news article related to an anime.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct AnimeNewsMeta {
     /// MyAnimeList ID of the news article.
     pub mal_id: u32,
@@ -425,7 +482,7 @@ pub struct AnimeNewsMeta {
     /// Title of the news article.
     pub title: String,
     /// Date the news article was published.
-    pub date: String,
+    pub date: ApiTimestamp,
     /// Username of the author.
     pub author_username: String,
     /// URL of the author's profile.
@@ -447,7 +504,7 @@ pub struct AnimeNewsMeta {
 pub type AnimeForumPosts = Vec<AnimeForumPost>;
 
 /// Represents a forum post related to an anime.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct AnimeForumPost {
     /// MyAnimeList ID of the forum post.
     pub mal_id: u32,
@@ -456,7 +513,7 @@ pub struct AnimeForumPost {
     /// Title of the forum post.
     pub title: String,
     /// Date the forum post was created.
-    pub date: Timestamp,
+    pub date: ApiTimestamp,
     /// Username of the author.
     pub author_username: String,
     /// URL of the author's profile.
@@ -468,7 +525,7 @@ pub struct AnimeForumPost {
 }
 
 /// Represents metadata about the last comment on a forum post.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct LastComment {
     /// URL of the last comment.
     pub url: String,
@@ -478,12 +535,12 @@ pub struct LastComment {
     pub author_url: String,
     /// Date the last comment was made (if available).
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub date: Option<Timestamp>,
+    pub date: Option<ApiTimestamp>,
 }
 
 /// Represents videos related to an anime.
 /// Root type for the `/anime/{id}/videos` endpoint.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct AnimeVideos {
     /// List of promotional videos.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -497,7 +554,7 @@ pub struct AnimeVideos {
 }
 
 /// Represents a promotional video for an anime.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct PromoVideo {
     /// Title of the promotional video.
     pub title: String,
@@ -510,10 +567,10 @@ pub struct PromoVideo {
 pub type AnimeVideosEpisodes = Vec<EpisodeVideo>;
 
 /// Represents an episode video for an anime.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct EpisodeVideo {
-    /// MyAnimeList ID of the episode video.
-    pub mal_id: u32,
+    /// Number of the episode the video belongs to.
+    pub mal_id: EpisodeNumber,
     /// URL of the episode video.
     pub url: String,
     /// Title of the episode video.
@@ -525,7 +582,7 @@ pub struct EpisodeVideo {
 }
 
 /// Represents a music video for an anime.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct MusicVideo {
     /// Title of the music video.
     pub title: String,
@@ -537,7 +594,7 @@ pub struct MusicVideo {
 }
 
 /// Represents metadata about a music video.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct MusicVideoMeta {
     /// Title of the music video (if available).
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -552,7 +609,7 @@ pub struct MusicVideoMeta {
 pub type AnimePictures = Vec<AnimePicture>;
 
 /// Represents a picture related to an anime.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct AnimePicture {
     /// Images associated with the anime picture.
     pub images: CommonImages,
@@ -560,7 +617,7 @@ pub struct AnimePicture {
 
 /// Represents statistics about an anime.
 /// Root type for the `/anime/{id}/statistics` endpoint.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct AnimeStatistics {
     /// Number of users watching the anime.
     pub watching: u32,
@@ -580,7 +637,7 @@ pub struct AnimeStatistics {
 }
 
 /// Represents a score given by users for an anime.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Score {
     /// Score given by users.
     pub score: u32,
@@ -592,7 +649,7 @@ pub struct Score {
 
 /// Represents additional information about an anime.
 /// Root type for the `/anime/{id}/moreinfo` endpoint.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct AnimeMoreInfo {
     /// Additional information about the anime.
     pub moreinfo: Option<String>,
@@ -603,10 +660,10 @@ pub struct AnimeMoreInfo {
 pub type AnimeRecommendations = Vec<AnimeRecommendation>;
 
 /// Represents a recommendation for an anime.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct AnimeRecommendation {
-    /// MyAnimeList ID of the recommendation.
-    pub mal_id: String,
+    /// `ID` of the recommendation, made of both recommended entries.
+    pub mal_id: RecommendationPairId,
     /// List of entries recommended.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub entry: Vec<EntryMeta>,
@@ -617,10 +674,10 @@ pub struct AnimeRecommendation {
 }
 
 /// Represents metadata about an entry (e.g., anime, manga).
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct EntryMeta {
     /// MyAnimeList ID of the entry.
-    pub mal_id: u32,
+    pub mal_id: AnimeId,
     /// MyAnimeList URL of the entry.
     pub url: String,
     /// Images associated with the entry.
@@ -630,7 +687,7 @@ pub struct EntryMeta {
 }
 
 /// Represents a user by their ID.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct UserById {
     /// URL of the user's profile.
     pub url: String,
@@ -643,7 +700,7 @@ pub struct UserById {
 pub type AnimeUserUpdates = Vec<AnimeUserUpdate>;
 
 /// Represents a user's update for an anime.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct AnimeUserUpdate {
     /// Metadata about the user.
     pub user: UserMeta,
@@ -659,11 +716,11 @@ pub struct AnimeUserUpdate {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub episodes_total: Option<u32>,
     /// Date of the user's update.
-    pub date: String,
+    pub date: ApiTimestamp,
 }
 
 /// Represents metadata about a user.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct UserMeta {
     /// Username of the user.
     pub username: String,
@@ -674,11 +731,11 @@ pub struct UserMeta {
 }
 
 /// Represents an image URL.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct ImageUrl {
     /// URL of the image.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub image_url: Option<String>,
+    pub image_url: Option<Url>,
 }
 
 /// Represents a list of reviews for an anime.
@@ -686,7 +743,7 @@ pub struct ImageUrl {
 pub type AnimeReviews = Vec<AnimeReview>;
 
 /// Represents a review for an anime.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct AnimeReview {
     /// MyAnimeList ID of the review.
     pub mal_id: u32,
@@ -698,7 +755,7 @@ pub struct AnimeReview {
     /// Reactions to the review.
     pub reactions: Reactions,
     /// Date the review was posted.
-    pub date: String,
+    pub date: ApiTimestamp,
     /// Content of the review.
     pub review: String,
     /// Score given in the review.
@@ -710,12 +767,15 @@ pub struct AnimeReview {
     pub is_spoiler: bool,
     /// Whether the review is preliminary.
     pub is_preliminary: bool,
-    /// Number of episodes watched by the reviewer.
-    pub episodes_watched: u32,
+    /// Number of episodes watched by the reviewer (if available).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub episodes_watched: Option<u32>,
+    /// User who wrote the review.
+    pub user: UserMeta,
 }
 
 /// Represents reactions to a review.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Reactions {
     /// Overall reaction count.
     pub overall: u32,
@@ -740,23 +800,23 @@ pub struct Reactions {
 pub type AnimeRelations = Vec<AnimeRelation>;
 
 /// Represents a relation between anime.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct AnimeRelation {
     /// Type of relation (e.g., sequel, prequel, etc.).
-    pub relation: String,
+    pub relation: RelationKind,
     /// List of related entries.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub entry: Vec<MalUrl>,
 }
 
 /// Represents a MyAnimeList URL.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct MalUrl {
     /// MyAnimeList ID of the entry.
     pub mal_id: u32,
     /// Type of the entry.
     #[serde(rename = "type")]
-    pub url_type: String,
+    pub url_type: EntryType,
     /// Name of the entry.
     pub name: String,
     /// URL of the entry.
@@ -765,21 +825,21 @@ pub struct MalUrl {
 
 /// Represents themes (openings and endings) of an anime.
 /// Root type for the `/anime/{id}/themes` endpoint.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct AnimeThemes {
     /// Theme data (openings and endings).
     pub data: Theme,
 }
 
 /// Represents opening and ending themes of an anime.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Theme {
     /// List of opening themes.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub openings: Vec<String>,
+    pub openings: Vec<ThemeSong>,
     /// List of ending themes.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub endings: Vec<String>,
+    pub endings: Vec<ThemeSong>,
 }
 
 /// Represents a list of external links related to an anime.
@@ -791,7 +851,7 @@ pub type AnimeExternal = Vec<ExternalLink>;
 pub type AnimeStreaming = Vec<ExternalLink>;
 
 /// Represents an external link.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ExternalLink {
     /// Name of the external link.
     pub name: String,
@@ -804,21 +864,21 @@ pub struct ExternalLink {
 pub type AnimeSearchResults = Vec<Anime>;
 
 /// Represents image URLs in different sizes.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct ImageUrls {
     /// URL of the image.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub image_url: Option<String>,
+    pub image_url: Option<Url>,
     /// URL of the small-sized image.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub small_image_url: Option<String>,
+    pub small_image_url: Option<Url>,
     /// URL of the large-sized image.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub large_image_url: Option<String>,
+    pub large_image_url: Option<Url>,
 }
 
 /// Represents basic trailer information.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct TrailerBase {
     /// YouTube ID of the trailer.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -829,10 +889,13 @@ pub struct TrailerBase {
     /// Embed URL of the trailer.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub embed_url: Option<String>,
+    /// Thumbnails of the trailer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub images: Option<TrailerImages>,
 }
 
 /// Represents detailed trailer information.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Trailer {
     /// YouTube ID of the trailer.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -848,7 +911,7 @@ pub struct Trailer {
 }
 
 /// Represents a date range.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct DateRange {
     /// Start date of the range (if available).
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -858,22 +921,22 @@ pub struct DateRange {
     pub to: Option<String>,
     /// Detailed date properties.
     pub prop: DateProp,
+    /// String representation of the date range (if available).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub string: Option<String>,
 }
 
 /// Represents detailed date properties.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct DateProp {
     /// Start date details.
     pub from: DatePropDetail,
     /// End date details.
     pub to: DatePropDetail,
-    /// String representation of the date range (if available).
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub string: Option<String>,
 }
 
 /// Represents detailed date properties (day, month, year).
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct DatePropDetail {
     /// Day of the date (if available).
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -887,7 +950,7 @@ pub struct DatePropDetail {
 }
 
 /// Represents broadcast details of an anime.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Broadcast {
     /// Day of the week the anime is broadcasted (if available).
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -904,7 +967,7 @@ pub struct Broadcast {
 }
 
 /// Represents a title (e.g., main title, English title, etc.).
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Title {
     /// Type of the title (e.g., "Default", "English", etc.).
     #[serde(rename = "type")]
@@ -914,30 +977,30 @@ pub struct Title {
 }
 
 /// Represents common image formats.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct CommonImages {
     /// JPG format image.
     pub jpg: ImageUrl,
 }
 
 /// Represents character images in different formats.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct CharacterImages {
     /// JPG format image.
     pub jpg: ImageUrl,
-    /// WebP format image.
-    pub webp: ImageUrl,
+    /// WebP format images.
+    pub webp: ImageUrls,
 }
 
 /// Represents images of a person (e.g., voice actor, staff member).
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct PeopleImages {
     /// JPG format image.
     pub jpg: ImageUrl,
 }
 
 /// Represents anime images in different formats.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct AnimeImages {
     /// JPG format images.
     pub jpg: ImageUrls,
@@ -946,7 +1009,7 @@ pub struct AnimeImages {
 }
 
 /// Represents user images in different formats.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct UserImages {
     /// JPG format image.
     pub jpg: ImageUrl,
@@ -955,21 +1018,21 @@ pub struct UserImages {
 }
 
 /// Represents trailer images in different sizes.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct TrailerImages {
     /// URL of the image.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub image_url: Option<String>,
+    pub image_url: Option<Url>,
     /// URL of the small-sized image.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub small_image_url: Option<String>,
+    pub small_image_url: Option<Url>,
     /// URL of the medium-sized image.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub medium_image_url: Option<String>,
+    pub medium_image_url: Option<Url>,
     /// URL of the large-sized image.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub large_image_url: Option<String>,
+    pub large_image_url: Option<Url>,
     /// URL of the maximum-sized image.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub maximum_image_url: Option<String>,
+    pub maximum_image_url: Option<Url>,
 }
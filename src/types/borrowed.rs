@@ -0,0 +1,412 @@
+//! Borrowed variants of the response types.
+//!
+//! The types in this module mirror their counterparts in [`super::anime`],
+//! but keep strings as [`Cow<'a, str>`] borrowing from the response body
+//! instead of allocating a `String` each. Strings containing JSON escapes
+//! still have to be unescaped and are allocated, and image URLs are parsed
+//! into the owned image types.
+//!
+//! They are meant to be deserialized from a
+//! [`RawResponse`](crate::api::RawResponse), which keeps the body alive, and
+//! can be turned into the owned types with `From`.
+
+use std::borrow::Cow;
+
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+};
+
+use super::{
+    AnimeId,
+    AnimeRating,
+    AnimeSeason,
+    AnimeStatus,
+    AnimeType,
+    EntryType,
+    RunningTime,
+    anime::{
+        self,
+        AnimeImages,
+        Broadcast,
+        DateRange,
+        TrailerImages,
+    },
+};
+
+/// Borrowed variant of [`anime::AnimeSearchResults`].
+pub type AnimeSearchResults<'a> = Vec<Anime<'a>>;
+
+/// Borrowed variant of [`anime::Anime`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Anime<'a> {
+    /// MyAnimeList ID.
+    pub mal_id: AnimeId,
+
+    /// MyAnimeList URL.
+    #[serde(borrow)]
+    pub url: Cow<'a, str>,
+
+    /// Images associated with the anime.
+    pub images: AnimeImages,
+
+    /// Trailer information.
+    #[serde(borrow)]
+    pub trailer: TrailerBase<'a>,
+
+    /// Whether the entry is pending approval on MyAnimeList.
+    pub approved: bool,
+
+    /// All titles associated with the anime.
+    #[serde(borrow)]
+    pub titles: Vec<Title<'a>>,
+
+    /// Main title of the anime.
+    #[serde(borrow)]
+    pub title: Cow<'a, str>,
+
+    /// English title of the anime (if available).
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub title_english: Option<Cow<'a, str>>,
+
+    /// Japanese title of the anime (if available).
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub title_japanese: Option<Cow<'a, str>>,
+
+    /// Other titles or synonyms for the anime.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "vec",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub title_synonyms: Vec<Cow<'a, str>>,
+
+    /// Type of the anime (e.g., TV, Movie, OVA, etc.).
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub anime_type: Option<AnimeType>,
+
+    /// Source material the anime is adapted from (e.g., manga, light novel,
+    /// etc.).
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub source: Option<Cow<'a, str>>,
+
+    /// Number of episodes in the anime (if available).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub episodes: Option<u32>,
+
+    /// Airing status of the anime.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<AnimeStatus>,
+
+    /// Whether the anime is currently airing.
+    pub airing: bool,
+
+    /// Date range during which the anime aired.
+    pub aired: DateRange,
+
+    /// Duration of each episode (if available).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration: Option<RunningTime>,
+
+    /// Audience rating of the anime.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rating: Option<AnimeRating>,
+
+    /// Average score of the anime (1.00 - 10.00).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<f32>,
+
+    /// Number of users who scored the anime.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scored_by: Option<u32>,
+
+    /// Ranking of the anime based on popularity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rank: Option<u32>,
+
+    /// Popularity rank of the anime.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub popularity: Option<u32>,
+
+    /// Number of users who have added the anime to their list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members: Option<u32>,
+
+    /// Number of users who have favorited the anime.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub favorites: Option<u32>,
+
+    /// Synopsis of the anime.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub synopsis: Option<Cow<'a, str>>,
+
+    /// Background information about the anime.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub background: Option<Cow<'a, str>>,
+
+    /// Season in which the anime aired.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub season: Option<AnimeSeason>,
+
+    /// Year in which the anime aired.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub year: Option<u32>,
+
+    /// Broadcast details of the anime.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub broadcast: Option<Broadcast>,
+
+    /// List of producers involved in the anime.
+    #[serde(borrow, default, skip_serializing_if = "Vec::is_empty")]
+    pub producers: Vec<MalUrl<'a>>,
+
+    /// List of licensors for the anime.
+    #[serde(borrow, default, skip_serializing_if = "Vec::is_empty")]
+    pub licensors: Vec<MalUrl<'a>>,
+
+    /// List of studios that produced the anime.
+    #[serde(borrow, default, skip_serializing_if = "Vec::is_empty")]
+    pub studios: Vec<MalUrl<'a>>,
+
+    /// List of genres associated with the anime.
+    #[serde(borrow, default, skip_serializing_if = "Vec::is_empty")]
+    pub genres: Vec<MalUrl<'a>>,
+
+    /// List of explicit genres associated with the anime.
+    #[serde(borrow, default, skip_serializing_if = "Vec::is_empty")]
+    pub explicit_genres: Vec<MalUrl<'a>>,
+
+    /// List of themes associated with the anime.
+    #[serde(borrow, default, skip_serializing_if = "Vec::is_empty")]
+    pub themes: Vec<MalUrl<'a>>,
+
+    /// List of demographics associated with the anime.
+    #[serde(borrow, default, skip_serializing_if = "Vec::is_empty")]
+    pub demographics: Vec<MalUrl<'a>>,
+}
+
+/// Borrowed variant of [`anime::TrailerBase`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TrailerBase<'a> {
+    /// YouTube ID of the trailer.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub youtube_id: Option<Cow<'a, str>>,
+    /// URL of the trailer.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub url: Option<Cow<'a, str>>,
+    /// Embed URL of the trailer.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub embed_url: Option<Cow<'a, str>>,
+    /// Thumbnails of the trailer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub images: Option<TrailerImages>,
+}
+
+/// Borrowed variant of [`anime::Title`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Title<'a> {
+    /// Type of the title (e.g., "Default", "English", etc.).
+    #[serde(rename = "type", borrow)]
+    pub title_type: Cow<'a, str>,
+    /// Title text.
+    #[serde(borrow)]
+    pub title: Cow<'a, str>,
+}
+
+/// Borrowed variant of [`anime::MalUrl`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MalUrl<'a> {
+    /// MyAnimeList ID of the entry.
+    pub mal_id: u32,
+    /// Type of the entry.
+    #[serde(rename = "type")]
+    pub url_type: EntryType,
+    /// Name of the entry.
+    #[serde(borrow)]
+    pub name: Cow<'a, str>,
+    /// URL of the entry.
+    #[serde(borrow)]
+    pub url: Cow<'a, str>,
+}
+
+/// `#[serde(borrow)]` only borrows a bare `Cow<str>`, not one nested in an
+/// `Option` or a `Vec`. Wrapping it in a struct makes it borrow again.
+#[derive(Deserialize)]
+struct Str<'a>(#[serde(borrow)] Cow<'a, str>);
+
+fn option<'de: 'a, 'a, D>(deserializer: D) -> Result<Option<Cow<'a, str>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<Str<'a>>::deserialize(deserializer)?.map(|s| s.0))
+}
+
+fn vec<'de: 'a, 'a, D>(deserializer: D) -> Result<Vec<Cow<'a, str>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Vec::<Str<'a>>::deserialize(deserializer)?
+        .into_iter()
+        .map(|s| s.0)
+        .collect())
+}
+
+fn owned(value: Option<Cow<'_, str>>) -> Option<String> {
+    value.map(Cow::into_owned)
+}
+
+impl From<Anime<'_>> for anime::Anime {
+    fn from(anime: Anime<'_>) -> Self {
+        let urls = |urls: Vec<MalUrl<'_>>| urls.into_iter().map(Into::into).collect();
+        Self {
+            mal_id: anime.mal_id,
+            url: anime.url.into_owned(),
+            images: anime.images,
+            trailer: anime.trailer.into(),
+            approved: anime.approved,
+            titles: anime.titles.into_iter().map(Into::into).collect(),
+            title: anime.title.into_owned(),
+            title_english: owned(anime.title_english),
+            title_japanese: owned(anime.title_japanese),
+            title_synonyms: anime
+                .title_synonyms
+                .into_iter()
+                .map(Cow::into_owned)
+                .collect(),
+            anime_type: anime.anime_type,
+            source: owned(anime.source),
+            episodes: anime.episodes,
+            status: anime.status,
+            airing: anime.airing,
+            aired: anime.aired,
+            duration: anime.duration,
+            rating: anime.rating,
+            score: anime.score,
+            scored_by: anime.scored_by,
+            rank: anime.rank,
+            popularity: anime.popularity,
+            members: anime.members,
+            favorites: anime.favorites,
+            synopsis: owned(anime.synopsis),
+            background: owned(anime.background),
+            season: anime.season,
+            year: anime.year,
+            broadcast: anime.broadcast,
+            producers: urls(anime.producers),
+            licensors: urls(anime.licensors),
+            studios: urls(anime.studios),
+            genres: urls(anime.genres),
+            explicit_genres: urls(anime.explicit_genres),
+            themes: urls(anime.themes),
+            demographics: urls(anime.demographics),
+        }
+    }
+}
+
+impl From<TrailerBase<'_>> for anime::TrailerBase {
+    fn from(trailer: TrailerBase<'_>) -> Self {
+        Self {
+            youtube_id: owned(trailer.youtube_id),
+            url: owned(trailer.url),
+            embed_url: owned(trailer.embed_url),
+            images: trailer.images,
+        }
+    }
+}
+
+impl From<Title<'_>> for anime::Title {
+    fn from(title: Title<'_>) -> Self {
+        Self {
+            title_type: title.title_type.into_owned(),
+            title: title.title.into_owned(),
+        }
+    }
+}
+
+impl From<MalUrl<'_>> for anime::MalUrl {
+    fn from(url: MalUrl<'_>) -> Self {
+        Self {
+            mal_id: url.mal_id,
+            url_type: url.url_type,
+            name: url.name.into_owned(),
+            url: url.url.into_owned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Root;
+
+    const ANIME: &str = include_str!("../../tests/fixtures/anime/anime.json");
+
+    #[test]
+    fn borrows_from_input() {
+        let root: Root<Anime<'_>> = serde_json::from_str(ANIME).unwrap();
+        let anime = root.data;
+        assert!(matches!(anime.title, Cow::Borrowed("Cowboy Bebop")));
+        assert!(matches!(anime.url, Cow::Borrowed(_)));
+        assert!(matches!(anime.title_japanese, Some(Cow::Borrowed(_))));
+        assert!(matches!(anime.studios[0].name, Cow::Borrowed(_)));
+        assert!(matches!(anime.titles[0].title, Cow::Borrowed(_)));
+
+        let mut value: serde_json::Value = serde_json::from_str(ANIME).unwrap();
+        value["data"]["title_synonyms"] = serde_json::json!(["Bebop", "Cowboy\nBebop"]);
+        let json = value.to_string();
+        let root: Root<Anime<'_>> = serde_json::from_str(&json).unwrap();
+        let synonyms = &root.data.title_synonyms;
+        assert!(matches!(synonyms[0], Cow::Borrowed("Bebop")));
+        // Escaped strings cannot be borrowed.
+        assert!(matches!(&synonyms[1], Cow::Owned(title) if title == "Cowboy\nBebop"));
+    }
+
+    #[test]
+    fn into_owned() {
+        let borrowed: Root<Anime<'_>> = serde_json::from_str(ANIME).unwrap();
+        let owned: Root<anime::Anime> = serde_json::from_str(ANIME).unwrap();
+        assert_eq!(anime::Anime::from(borrowed.data), owned.data);
+    }
+}
@@ -0,0 +1,247 @@
+use std::time::SystemTime;
+
+use iso8601_timestamp::Timestamp;
+use jiff::{
+    Zoned,
+    civil::{
+        Time,
+        Weekday,
+    },
+    tz::TimeZone,
+};
+use thiserror::Error;
+
+use super::anime::Broadcast;
+
+/// Weekly broadcast slot of an airing anime.
+///
+/// Built from the string fields of [`Broadcast`] with
+/// [`Broadcast::schedule`], or directly with [`BroadcastSchedule::new`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BroadcastSchedule {
+    weekday: Weekday,
+    time: Time,
+    timezone: TimeZone,
+}
+
+impl BroadcastSchedule {
+    /// Create a schedule airing every `weekday` at `time` in `timezone`.
+    pub fn new(weekday: Weekday, time: Time, timezone: TimeZone) -> Self {
+        Self {
+            weekday,
+            time,
+            timezone,
+        }
+    }
+
+    /// Day of the week of the broadcast, in the broadcast time zone.
+    pub fn weekday(&self) -> Weekday {
+        self.weekday
+    }
+
+    /// Local time of the broadcast, in the broadcast time zone.
+    pub fn time(&self) -> Time {
+        self.time
+    }
+
+    /// Time zone the broadcast slot is defined in, usually `Asia/Tokyo`.
+    pub fn timezone(&self) -> &TimeZone {
+        &self.timezone
+    }
+
+    /// Returns the first broadcast strictly after `after`.
+    ///
+    /// Returns `None` if that instant is outside of the range supported by
+    /// [`jiff`].
+    pub fn next_airing(&self, after: Timestamp) -> Option<Timestamp> {
+        let airing = self.next_airing_zoned(to_jiff(after)?)?;
+        Some(SystemTime::from(airing.timestamp()).into())
+    }
+
+    /// Same as [`BroadcastSchedule::next_airing`], as a date and time in
+    /// `timezone`.
+    pub fn next_airing_in(&self, after: Timestamp, timezone: &TimeZone) -> Option<Zoned> {
+        self.next_airing_zoned(to_jiff(after)?)
+            .map(|airing| airing.with_time_zone(timezone.clone()))
+    }
+
+    fn next_airing_zoned(&self, after: jiff::Timestamp) -> Option<Zoned> {
+        let today = after.to_zoned(self.timezone.clone()).date();
+        let mut date = if today.weekday() == self.weekday {
+            today
+        } else {
+            today.nth_weekday(1, self.weekday).ok()?
+        };
+        loop {
+            let airing = date
+                .to_datetime(self.time)
+                .to_zoned(self.timezone.clone())
+                .ok()?;
+            if airing.timestamp() > after {
+                return Some(airing);
+            }
+            date = date.nth_weekday(1, self.weekday).ok()?;
+        }
+    }
+}
+
+fn to_jiff(timestamp: Timestamp) -> Option<jiff::Timestamp> {
+    jiff::Timestamp::try_from(SystemTime::from(timestamp)).ok()
+}
+
+/// Errors raised while converting a [`Broadcast`] into a [`BroadcastSchedule`].
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BroadcastError {
+    /// A field needed for the schedule is not set, e.g. for finished anime.
+    #[error("broadcast {0} is missing")]
+    Missing(&'static str),
+    /// The day is not a day of the week.
+    #[error("invalid broadcast day `{0}`")]
+    Day(String),
+    /// The time is not in `HH:MM` form.
+    #[error("invalid broadcast time `{0}`")]
+    Time(String),
+    /// The time zone is not a known IANA time zone.
+    #[error("unknown broadcast time zone `{0}`")]
+    TimeZone(String),
+}
+
+fn parse_weekday(day: &str) -> Option<Weekday> {
+    let day = day.trim().to_ascii_lowercase();
+    let day = day.strip_suffix('s').unwrap_or(&day);
+    let weekday = match day {
+        "monday" => Weekday::Monday,
+        "tuesday" => Weekday::Tuesday,
+        "wednesday" => Weekday::Wednesday,
+        "thursday" => Weekday::Thursday,
+        "friday" => Weekday::Friday,
+        "saturday" => Weekday::Saturday,
+        "sunday" => Weekday::Sunday,
+        _ => return None,
+    };
+    Some(weekday)
+}
+
+impl TryFrom<&Broadcast> for BroadcastSchedule {
+    type Error = BroadcastError;
+
+    fn try_from(broadcast: &Broadcast) -> Result<Self, Self::Error> {
+        let day = broadcast
+            .day
+            .as_deref()
+            .ok_or(BroadcastError::Missing("day"))?;
+        let time = broadcast
+            .time
+            .as_deref()
+            .ok_or(BroadcastError::Missing("time"))?;
+        let timezone = broadcast
+            .timezone
+            .as_deref()
+            .ok_or(BroadcastError::Missing("timezone"))?;
+
+        Ok(Self {
+            weekday: parse_weekday(day).ok_or_else(|| BroadcastError::Day(day.to_owned()))?,
+            time: Time::strptime("%H:%M", time)
+                .map_err(|_| BroadcastError::Time(time.to_owned()))?,
+            timezone: TimeZone::get(timezone)
+                .map_err(|_| BroadcastError::TimeZone(timezone.to_owned()))?,
+        })
+    }
+}
+
+impl Broadcast {
+    /// Parses the broadcast slot into a typed [`BroadcastSchedule`].
+    pub fn schedule(&self) -> Result<BroadcastSchedule, BroadcastError> {
+        self.try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn broadcast(day: &str, time: &str, timezone: &str) -> Broadcast {
+        Broadcast {
+            day: Some(day.to_owned()),
+            time: Some(time.to_owned()),
+            timezone: Some(timezone.to_owned()),
+            string: None,
+        }
+    }
+
+    fn ts(ts: &str) -> Timestamp {
+        Timestamp::parse(ts).unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        let schedule = broadcast("Saturdays", "01:00", "Asia/Tokyo")
+            .schedule()
+            .unwrap();
+        assert_eq!(schedule.weekday(), Weekday::Saturday);
+        assert_eq!(schedule.time(), Time::constant(1, 0, 0, 0));
+        assert_eq!(schedule.timezone().iana_name(), Some("Asia/Tokyo"));
+
+        let unknown = Broadcast {
+            day: None,
+            time: None,
+            timezone: None,
+            string: Some("Unknown".to_owned()),
+        };
+        assert_eq!(unknown.schedule(), Err(BroadcastError::Missing("day")));
+        assert_eq!(
+            broadcast("Someday", "01:00", "Asia/Tokyo").schedule(),
+            Err(BroadcastError::Day("Someday".to_owned()))
+        );
+        assert_eq!(
+            broadcast("Sundays", "25:00", "Asia/Tokyo").schedule(),
+            Err(BroadcastError::Time("25:00".to_owned()))
+        );
+        assert_eq!(
+            broadcast("Sundays", "01:00", "Asia/Nowhere").schedule(),
+            Err(BroadcastError::TimeZone("Asia/Nowhere".to_owned()))
+        );
+    }
+
+    #[test]
+    fn next_airing() {
+        let schedule = broadcast("Saturdays", "01:00", "Asia/Tokyo")
+            .schedule()
+            .unwrap();
+        let next = |after| schedule.next_airing(ts(after)).unwrap();
+
+        // Wednesday in Japan.
+        assert_eq!(next("2025-01-15T00:00:00Z"), ts("2025-01-17T16:00:00Z"));
+        // Saturday 00:30 in Japan, airing later the same day.
+        assert_eq!(next("2025-01-17T15:30:00Z"), ts("2025-01-17T16:00:00Z"));
+        // Exactly at airing time, the next one is a week later.
+        assert_eq!(next("2025-01-17T16:00:00Z"), ts("2025-01-24T16:00:00Z"));
+        // Saturday afternoon in Japan.
+        assert_eq!(next("2025-01-18T05:00:00Z"), ts("2025-01-24T16:00:00Z"));
+    }
+
+    #[test]
+    fn next_airing_in() {
+        let schedule = broadcast("Sundays", "23:30", "Asia/Tokyo")
+            .schedule()
+            .unwrap();
+        let new_york = TimeZone::get("America/New_York").unwrap();
+
+        let winter = schedule
+            .next_airing_in(ts("2025-01-15T00:00:00Z"), &new_york)
+            .unwrap();
+        assert_eq!(
+            winter.to_string(),
+            "2025-01-19T09:30:00-05:00[America/New_York]"
+        );
+
+        let summer = schedule
+            .next_airing_in(ts("2025-07-15T00:00:00Z"), &new_york)
+            .unwrap();
+        assert_eq!(
+            summer.to_string(),
+            "2025-07-20T10:30:00-04:00[America/New_York]"
+        );
+    }
+}
@@ -5,7 +5,7 @@ use serde::{
 
 /// Represents the root structure of a response, containing data and optional.
 /// pagination.
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Root<T> {
     /// The main data payload of the response.
     pub data: T,
@@ -15,19 +15,22 @@ pub struct Root<T> {
 }
 
 /// Represents pagination details for a response.
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Pagination {
     /// The last visible page in the pagination.
     pub last_visible_page: u64,
     /// Indicates whether there is a next page available.
     pub has_next_page: bool,
+    /// The current page, only sent by some endpoints.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_page: Option<u64>,
     /// Optional details about the pagination items (count, total, per_page).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub items: Option<PaginationItems>,
 }
 
 /// Represents pagination items, including count, total, and per-page details.
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct PaginationItems {
     /// Number of items on the current page.
     pub count: u64,
@@ -37,68 +40,140 @@ pub struct PaginationItems {
     pub per_page: u64,
 }
 
+/// Implements `as_str` and the conversions from and to `String` used by serde
+/// for an enum with an `Unknown(String)` fallback variant.
+macro_rules! string_enum {
+    ($name:ident { $($variant:ident => $value:literal $(| $alias:literal)*,)* }) => {
+        impl $name {
+            /// Value as returned by the API.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $value,)*
+                    Self::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                match value.as_str() {
+                    $($value $(| $alias)* => Self::$variant,)*
+                    _ => Self::Unknown(value),
+                }
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                match value {
+                    $name::Unknown(value) => value,
+                    value => value.as_str().to_owned(),
+                }
+            }
+        }
+    };
+}
+
 /// Represents the type of anime (e.g., TV, Movie, OVA, etc.).
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
+#[non_exhaustive]
 pub enum AnimeType {
     /// TV series.
-    #[serde(rename = "TV")]
     Tv,
     /// Original Video Animation (OVA).
-    #[serde(rename = "OVA")]
     Ova,
     /// Original Net Animation (ONA).
-    #[serde(rename = "ONA")]
     Ona,
     /// Movie.
     Movie,
     /// Special episode.
     Special,
-    /// Music video or promotional video.
+    /// TV special.
+    TvSpecial,
+    /// Music video.
     Music,
+    /// Commercial message.
+    Cm,
+    /// Promotional video.
+    Pv,
+    /// A type not known to this crate, as returned by the API.
+    Unknown(String),
+}
+
+string_enum! {
+AnimeType {
+    Tv => "TV",
+    Ova => "OVA",
+    Ona => "ONA",
+    Movie => "Movie",
+    Special => "Special",
+    TvSpecial => "TV Special",
+    Music => "Music",
+    Cm => "CM",
+    Pv => "PV",
+}
 }
 
 /// Represents the airing status of an anime.
-#[derive(Serialize, Deserialize, Debug)]
-#[serde(rename_all = "lowercase")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
+#[non_exhaustive]
 pub enum AnimeStatus {
     /// The anime is currently airing.
-    #[serde(rename = "Currently Airing")]
     Airing,
     /// The anime has finished airing.
-    #[serde(rename = "Finished Airing")]
     Complete,
     /// The anime has not yet aired.
-    #[serde(rename = "Not yet aired")]
     Upcoming,
+    /// A status not known to this crate, as returned by the API.
+    Unknown(String),
+}
+
+string_enum! {
+AnimeStatus {
+    Airing => "Currently Airing",
+    Complete => "Finished Airing",
+    Upcoming => "Not yet aired",
+}
 }
 
 /// Represents the audience rating of an anime.
-#[derive(Serialize, Deserialize, Debug)]
-#[serde(rename_all = "lowercase")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
+#[non_exhaustive]
 pub enum AnimeRating {
     /// Suitable for all ages.
-    #[serde(rename = "G - All Ages")]
     G,
     /// Suitable for children.
-    #[serde(rename = "PG - Children")]
     Pg,
     /// Suitable for teens 13 or older.
-    #[serde(rename = "PG-13 - Teens 13 or older")]
     Pg13,
     /// Restricted to viewers 17 or older (violence & profanity).
-    #[serde(rename = "R - 17+ (violence & profanity)")]
     R17,
     /// Restricted to viewers 17 or older (mild nudity).
-    #[serde(rename = "R+ - Mild Nudity")]
     R,
     /// Hentai (explicit content).
-    #[serde(rename = "Rx - Hentai")]
     Rx,
+    /// A rating not known to this crate, as returned by the API.
+    Unknown(String),
+}
+
+string_enum! {
+AnimeRating {
+    G => "G - All Ages",
+    Pg => "PG - Children",
+    Pg13 => "PG-13 - Teens 13 or older",
+    R17 => "R - 17+ (violence & profanity)",
+    R => "R+ - Mild Nudity",
+    Rx => "Rx - Hentai",
+}
 }
 
 /// Represents the season in which an anime aired.
-#[derive(Serialize, Deserialize, Debug)]
-#[serde(rename_all = "lowercase")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
+#[non_exhaustive]
 pub enum AnimeSeason {
     /// Anime aired in the summer season.
     Summer,
@@ -108,4 +183,123 @@ pub enum AnimeSeason {
     Spring,
     /// Anime aired in the fall season.
     Fall,
+    /// A season not known to this crate, as returned by the API.
+    Unknown(String),
+}
+
+string_enum! {
+AnimeSeason {
+    Summer => "summer",
+    Winter => "winter",
+    Spring => "spring",
+    Fall => "fall",
+}
+}
+
+/// Represents how two entries are related (e.g., sequel, adaptation, etc.).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
+#[non_exhaustive]
+pub enum RelationKind {
+    /// The related entry continues the story.
+    Sequel,
+    /// The related entry precedes the story.
+    Prequel,
+    /// The related entry is a side story.
+    SideStory,
+    /// The related entry is the main story of this side story.
+    ParentStory,
+    /// The related entry is an adaptation, e.g. the source manga.
+    Adaptation,
+    /// The related entry retells the same story differently.
+    AlternativeVersion,
+    /// The related entry takes place in the same setting.
+    AlternativeSetting,
+    /// The related entry is a spin-off.
+    SpinOff,
+    /// The related entry summarizes the story.
+    Summary,
+    /// The related entry shares characters.
+    Character,
+    /// Any other relation.
+    Other,
+    /// The related entry is the full story of this summary.
+    FullStory,
+    /// A relation not known to this crate, as returned by the API.
+    Unknown(String),
+}
+
+string_enum! {
+RelationKind {
+    Sequel => "Sequel",
+    Prequel => "Prequel",
+    SideStory => "Side Story",
+    ParentStory => "Parent Story",
+    Adaptation => "Adaptation",
+    AlternativeVersion => "Alternative Version",
+    AlternativeSetting => "Alternative Setting",
+    SpinOff => "Spin-Off" | "Spin-off",
+    Summary => "Summary",
+    Character => "Character",
+    Other => "Other",
+    FullStory => "Full Story",
+}
+}
+
+/// Represents the kind of resource a MyAnimeList URL points to.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
+#[non_exhaustive]
+pub enum EntryType {
+    /// An anime.
+    Anime,
+    /// A manga.
+    Manga,
+    /// A person.
+    People,
+    /// A character.
+    Character,
+    /// A type not known to this crate, as returned by the API.
+    Unknown(String),
+}
+
+string_enum! {
+EntryType {
+    Anime => "anime",
+    Manga => "manga",
+    People => "people",
+    Character => "character",
+}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_values_are_never_unknown() {
+        assert_eq!(AnimeType::from("TV".to_owned()), AnimeType::Tv);
+        assert_eq!(
+            RelationKind::from("Sequel".to_owned()),
+            RelationKind::Sequel
+        );
+        let tv: AnimeType = serde_json::from_str(r#""TV""#).unwrap();
+        assert_eq!(tv, AnimeType::Tv);
+    }
+
+    #[test]
+    fn unknown_values_round_trip() {
+        let kind: AnimeType = serde_json::from_str(r#""Hologram""#).unwrap();
+        let AnimeType::Unknown(value) = &kind else {
+            panic!("expected an unknown type, got {kind:?}");
+        };
+        assert_eq!(value, "Hologram");
+        assert_eq!(kind.as_str(), "Hologram");
+        assert_eq!(serde_json::to_string(&kind).unwrap(), r#""Hologram""#);
+        assert_eq!(AnimeType::from(String::from(kind.clone())), kind);
+
+        let entry: EntryType = serde_json::from_str(r#""novel""#).unwrap();
+        assert!(matches!(&entry, EntryType::Unknown(value) if value == "novel"));
+        assert_eq!(serde_json::to_string(&entry).unwrap(), r#""novel""#);
+    }
 }
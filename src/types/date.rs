@@ -0,0 +1,443 @@
+use std::{
+    convert::Infallible,
+    fmt,
+    str::FromStr,
+};
+
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+    de,
+};
+use thiserror::Error;
+
+use super::anime::{
+    DatePropDetail,
+    DateRange,
+};
+
+/// A date with an optional month and day.
+///
+/// MyAnimeList often only knows the year or the month of an event, this type
+/// represents such dates. It is formatted as `YYYY`, `YYYY-MM` or `YYYY-MM-DD`.
+///
+/// Dates are ordered chronologically, a less precise date sorts before the more
+/// precise dates it contains (`1998` < `1998-04` < `1998-04-03`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PartialDate {
+    year: u16,
+    month: Option<u8>,
+    day: Option<u8>,
+}
+
+/// Errors that occur when creating a [`PartialDate`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum DateError {
+    /// The string is not formatted as `YYYY[-MM[-DD]]`.
+    #[error("invalid date format: `{0}`, expected `YYYY[-MM[-DD]]`")]
+    Format(String),
+    /// The year is out of the `1..=9999` range.
+    #[error("invalid year: {0}")]
+    Year(u16),
+    /// The month is out of the `1..=12` range.
+    #[error("invalid month: {0}")]
+    Month(u8),
+    /// The day does not exist in the given month.
+    #[error("invalid day: {year:04}-{month:02}-{day:02}")]
+    Day {
+        /// Year of the date.
+        year: u16,
+        /// Month of the date.
+        month: u8,
+        /// Day of the date.
+        day: u8,
+    },
+    /// A day was given without a month.
+    #[error("day given without a month")]
+    DayWithoutMonth,
+    /// No year was given.
+    #[error("date has no year")]
+    MissingYear,
+}
+
+impl PartialDate {
+    /// Create a new date, validating its components.
+    pub fn new(year: u16, month: Option<u8>, day: Option<u8>) -> Result<Self, DateError> {
+        if !(1..=9999).contains(&year) {
+            return Err(DateError::Year(year));
+        }
+        match (month, day) {
+            (None, Some(_)) => return Err(DateError::DayWithoutMonth),
+            (Some(month), _) if !(1..=12).contains(&month) => return Err(DateError::Month(month)),
+            (Some(month), Some(day)) if day == 0 || day > days_in_month(year, month) => {
+                return Err(DateError::Day { year, month, day });
+            }
+            _ => {}
+        }
+        Ok(Self { year, month, day })
+    }
+
+    /// Create a date with only a year.
+    pub fn from_year(year: u16) -> Result<Self, DateError> {
+        Self::new(year, None, None)
+    }
+
+    /// Create a date with a year and a month.
+    pub fn from_year_month(year: u16, month: u8) -> Result<Self, DateError> {
+        Self::new(year, Some(month), None)
+    }
+
+    /// Create a full date.
+    pub fn from_ymd(year: u16, month: u8, day: u8) -> Result<Self, DateError> {
+        Self::new(year, Some(month), Some(day))
+    }
+
+    /// Year of the date.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Month of the date (if known).
+    pub fn month(&self) -> Option<u8> {
+        self.month
+    }
+
+    /// Day of the date (if known).
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    /// Returns `true` if both month and day are known.
+    pub fn is_complete(&self) -> bool {
+        self.day.is_some()
+    }
+
+    /// First day of the period covered by this date.
+    pub fn first_day(&self) -> Self {
+        let month = self.month.unwrap_or(1);
+        Self {
+            year: self.year,
+            month: Some(month),
+            day: Some(self.day.unwrap_or(1)),
+        }
+    }
+
+    /// Last day of the period covered by this date.
+    pub fn last_day(&self) -> Self {
+        let month = self.month.unwrap_or(12);
+        Self {
+            year: self.year,
+            month: Some(month),
+            day: Some(self.day.unwrap_or_else(|| days_in_month(self.year, month))),
+        }
+    }
+
+    /// Returns `true` if `other` falls within the period covered by this date,
+    /// e.g. `1998` contains `1998-04-03` but not the other way around.
+    pub fn contains(&self, other: &Self) -> bool {
+        self.first_day() <= other.first_day() && other.last_day() <= self.last_day()
+    }
+
+    fn to_civil(self) -> jiff::civil::Date {
+        let date = self.first_day();
+        jiff::civil::date(
+            date.year as i16,
+            date.month.unwrap_or(1) as i8,
+            date.day.unwrap_or(1) as i8,
+        )
+    }
+
+    /// Writes the date the way MyAnimeList does: `Apr 3, 1998`, `Apr 1998` or
+    /// `1998`.
+    fn fmt_mal(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const MONTHS: [&str; 12] = [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ];
+        match (self.month, self.day) {
+            (Some(month), Some(day)) => {
+                write!(f, "{} {day}, {}", MONTHS[month as usize - 1], self.year)
+            }
+            (Some(month), None) => write!(f, "{} {}", MONTHS[month as usize - 1], self.year),
+            _ => write!(f, "{}", self.year),
+        }
+    }
+}
+
+/// A period between two [`PartialDate`]s, e.g. the airing dates of an anime.
+///
+/// A missing `to` means the period has not ended yet or its end is unknown.
+/// Jikan also leaves it empty for single-day releases such as movies, set it
+/// to `from` for those to get MyAnimeList's formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PartialDateRange {
+    /// Start of the period, if known.
+    pub from: Option<PartialDate>,
+    /// End of the period, if known.
+    pub to: Option<PartialDate>,
+}
+
+impl PartialDateRange {
+    /// Returns `true` if `date` falls within the period.
+    ///
+    /// Imprecise bounds are widened to the whole month or year they cover, an
+    /// unknown end is treated as open-ended. Always `false` without a start.
+    pub fn contains(&self, date: &PartialDate) -> bool {
+        let Some(from) = self.from else {
+            return false;
+        };
+        from.first_day() <= date.first_day()
+            && self.to.is_none_or(|to| date.last_day() <= to.last_day())
+    }
+
+    /// Number of days between the start and the end of the period.
+    ///
+    /// Returns `None` unless both bounds are complete dates.
+    pub fn days(&self) -> Option<u32> {
+        let (from, to) = (self.from?, self.to?);
+        if !from.is_complete() || !to.is_complete() {
+            return None;
+        }
+        let days = to.to_civil().since(from.to_civil()).ok()?.get_days();
+        u32::try_from(days).ok()
+    }
+}
+
+/// Formats the period like MyAnimeList, e.g. `Apr 3, 1998 to Apr 24, 1999`,
+/// `Oct 5, 2024 to ?` or `Sep 1, 2001` for single-day periods.
+impl fmt::Display for PartialDateRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(from) = self.from else {
+            return f.write_str("Not available");
+        };
+        from.fmt_mal(f)?;
+        match self.to {
+            Some(to) if to == from => Ok(()),
+            Some(to) => {
+                f.write_str(" to ")?;
+                to.fmt_mal(f)
+            }
+            None => f.write_str(" to ?"),
+        }
+    }
+}
+
+fn is_leap_year(year: u16) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl fmt::Display for PartialDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}", self.year)?;
+        if let Some(month) = self.month {
+            write!(f, "-{month:02}")?;
+        }
+        if let Some(day) = self.day {
+            write!(f, "-{day:02}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for PartialDate {
+    type Err = DateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let format_err = || DateError::Format(s.to_owned());
+        let number = |part: &str, len: usize| {
+            if part.len() == len && part.bytes().all(|b| b.is_ascii_digit()) {
+                part.parse::<u16>().map_err(|_| format_err())
+            } else {
+                Err(format_err())
+            }
+        };
+
+        match s.split('-').collect::<Vec<_>>().as_slice() {
+            [year] => Self::new(number(year, 4)?, None, None),
+            [year, month] => Self::new(number(year, 4)?, Some(number(month, 2)? as u8), None),
+            [year, month, day] => Self::new(
+                number(year, 4)?,
+                Some(number(month, 2)? as u8),
+                Some(number(day, 2)? as u8),
+            ),
+            _ => Err(format_err()),
+        }
+    }
+}
+
+impl TryFrom<&str> for PartialDate {
+    type Error = DateError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<String> for PartialDate {
+    type Error = DateError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<&DatePropDetail> for PartialDate {
+    type Error = DateError;
+
+    fn try_from(detail: &DatePropDetail) -> Result<Self, Self::Error> {
+        let year = detail.year.ok_or(DateError::MissingYear)?;
+        Self::new(
+            u16::try_from(year).unwrap_or(u16::MAX),
+            detail
+                .month
+                .map(|month| u8::try_from(month).unwrap_or(u8::MAX)),
+            detail.day.map(|day| u8::try_from(day).unwrap_or(u8::MAX)),
+        )
+    }
+}
+
+impl DateRange {
+    /// Returns the period as [`PartialDate`]s, built from the day, month and
+    /// year in [`DateRange::prop`].
+    pub fn dates(&self) -> PartialDateRange {
+        PartialDateRange {
+            from: (&self.prop.from).try_into().ok(),
+            to: (&self.prop.to).try_into().ok(),
+        }
+    }
+}
+
+impl From<Infallible> for DateError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl Serialize for PartialDate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PartialDate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> PartialDate {
+        s.parse().unwrap()
+    }
+
+    fn range(from: Option<&str>, to: Option<&str>) -> PartialDateRange {
+        PartialDateRange {
+            from: from.map(date),
+            to: to.map(date),
+        }
+    }
+
+    #[test]
+    fn ordering_and_contains() {
+        assert!(date("1998") < date("1998-04"));
+        assert!(date("1998-04") < date("1998-04-03"));
+        assert!(date("1998-12-31") < date("1999"));
+
+        assert!(date("1998").contains(&date("1998-04-03")));
+        assert!(date("1998-04").contains(&date("1998-04")));
+        assert!(!date("1998-04-03").contains(&date("1998-04")));
+        assert!(!date("1998-04").contains(&date("1998-05-01")));
+        assert_eq!(date("2024-02").last_day(), date("2024-02-29"));
+    }
+
+    #[test]
+    fn range_contains() {
+        let aired = range(Some("1998-04-03"), Some("1999-04-24"));
+        assert!(aired.contains(&date("1998-10-24")));
+        assert!(!aired.contains(&date("1999-04")));
+        assert!(!aired.contains(&date("1998-04-02")));
+
+        let airing = range(Some("2024-10"), None);
+        assert!(airing.contains(&date("2030-01-01")));
+        assert!(airing.contains(&date("2024-10-01")));
+        assert!(!range(None, None).contains(&date("2024")));
+    }
+
+    #[test]
+    fn range_days() {
+        assert_eq!(
+            range(Some("1998-04-03"), Some("1999-04-24")).days(),
+            Some(386)
+        );
+        assert_eq!(
+            range(Some("2001-09-01"), Some("2001-09-01")).days(),
+            Some(0)
+        );
+        assert_eq!(range(Some("1998-04"), Some("1999-04-24")).days(), None);
+        assert_eq!(range(Some("1998-04-03"), None).days(), None);
+    }
+
+    #[test]
+    fn range_display() {
+        let cases = [
+            (
+                range(Some("1998-04-03"), Some("1999-04-24")),
+                "Apr 3, 1998 to Apr 24, 1999",
+            ),
+            (range(Some("2024-10-05"), None), "Oct 5, 2024 to ?"),
+            (range(Some("2001-09-01"), Some("2001-09-01")), "Sep 1, 2001"),
+            (range(Some("2026-07"), None), "Jul 2026 to ?"),
+            (range(Some("2027"), Some("2028")), "2027 to 2028"),
+            (range(None, None), "Not available"),
+        ];
+        for (range, expected) in cases {
+            assert_eq!(range.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn from_date_prop_detail() {
+        let detail = |day, month, year| DatePropDetail { day, month, year };
+        assert_eq!(
+            PartialDate::try_from(&detail(Some(3), Some(4), Some(1998))),
+            Ok(date("1998-04-03"))
+        );
+        assert_eq!(
+            PartialDate::try_from(&detail(None, None, Some(2026))),
+            Ok(date("2026"))
+        );
+        assert_eq!(
+            PartialDate::try_from(&detail(None, None, None)),
+            Err(DateError::MissingYear)
+        );
+        assert_eq!(
+            PartialDate::try_from(&detail(Some(31), Some(2), Some(2024))),
+            Err(DateError::Day {
+                year: 2024,
+                month: 2,
+                day: 31
+            })
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use std::{
+    fmt,
+    time::Duration,
+};
+
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+/// Running time of an anime as shown by MyAnimeList, e.g. `"24 min per ep"`
+/// or `"1 hr 55 min"`.
+///
+/// The raw string is kept as-is, the parsed [`Duration`] is `None` when the
+/// value is `"Unknown"` or not understood.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
+pub struct RunningTime {
+    raw: String,
+    duration: Option<Duration>,
+    per_episode: bool,
+}
+
+impl RunningTime {
+    /// Returns the string as sent by Jikan.
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    /// Returns the parsed running time, if known.
+    ///
+    /// For TV series this is the length of a single episode, see
+    /// [`RunningTime::is_per_episode`].
+    pub fn duration(&self) -> Option<Duration> {
+        self.duration
+    }
+
+    /// Whether the duration is given per episode (`"... per ep"`).
+    pub fn is_per_episode(&self) -> bool {
+        self.per_episode
+    }
+
+    fn parse(raw: &str) -> Option<(Duration, bool)> {
+        let (raw, per_episode) = match raw.trim().strip_suffix("per ep") {
+            Some(rest) => (rest, true),
+            None => (raw, false),
+        };
+        let mut tokens = raw.split_whitespace();
+        let mut seconds = 0;
+        let mut parsed_any = false;
+        while let Some(value) = tokens.next() {
+            let value: u64 = value.parse().ok()?;
+            let unit = match tokens.next()? {
+                "hr" | "hrs" => 3600,
+                "min" | "mins" => 60,
+                "sec" | "secs" => 1,
+                _ => return None,
+            };
+            seconds = value.checked_mul(unit)?.checked_add(seconds)?;
+            parsed_any = true;
+        }
+        parsed_any.then(|| (Duration::from_secs(seconds), per_episode))
+    }
+}
+
+impl From<String> for RunningTime {
+    fn from(raw: String) -> Self {
+        let (duration, per_episode) = match Self::parse(&raw) {
+            Some((duration, per_episode)) => (Some(duration), per_episode),
+            None => (None, false),
+        };
+        Self {
+            raw,
+            duration,
+            per_episode,
+        }
+    }
+}
+
+impl From<&str> for RunningTime {
+    fn from(raw: &str) -> Self {
+        raw.to_owned().into()
+    }
+}
+
+impl From<RunningTime> for String {
+    fn from(value: RunningTime) -> Self {
+        value.raw
+    }
+}
+
+impl fmt::Display for RunningTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let cases = [
+            ("24 min per ep", Some(24 * 60), true),
+            ("1 hr 55 min", Some(3600 + 55 * 60), false),
+            ("1 hr per ep", Some(3600), true),
+            ("3 min 20 sec", Some(200), false),
+            ("45 sec per ep", Some(45), true),
+            ("Unknown", None, false),
+            ("", None, false),
+            ("24 minutes", None, false),
+            ("18446744073709551615 hr", None, false),
+            ("18446744073709551615 sec 1 sec", None, false),
+        ];
+        for (raw, seconds, per_episode) in cases {
+            let time = RunningTime::from(raw);
+            assert_eq!(time.as_str(), raw);
+            assert_eq!(time.duration(), seconds.map(Duration::from_secs), "{raw}");
+            assert_eq!(time.is_per_episode(), per_episode, "{raw}");
+        }
+    }
+}
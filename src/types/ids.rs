@@ -0,0 +1,427 @@
+use std::{
+    fmt,
+    str::FromStr,
+};
+
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use thiserror::Error;
+use url::Url;
+
+use super::{
+    EntryType,
+    anime::MalUrl,
+};
+
+macro_rules! mal_id {
+    ($(#[$meta:meta])* $name:ident, url: $url_type:ident $path:literal) => {
+        mal_id!($(#[$meta])* $name);
+
+        impl TryFrom<&MalUrl> for $name {
+            type Error = ParseIdError;
+
+            /// Take the `ID` of a [`MalUrl`], checking that the URL points to
+            #[doc = concat!("`", $path, "{id}`.")]
+            fn try_from(url: &MalUrl) -> Result<Self, Self::Error> {
+                check_url(url, EntryType::$url_type, $path).map(Self)
+            }
+        }
+    };
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[serde(transparent)]
+        pub struct $name(pub u32);
+
+        impl From<u32> for $name {
+            fn from(id: u32) -> Self {
+                Self(id)
+            }
+        }
+
+        impl From<$name> for u32 {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    };
+}
+
+mal_id! {
+    /// `ID` of an anime.
+    AnimeId, url: Anime "/anime/"
+}
+
+mal_id! {
+    /// `ID` of a manga.
+    MangaId, url: Manga "/manga/"
+}
+
+mal_id! {
+    /// `ID` of a person (voice actor, staff member, ...).
+    PersonId, url: People "/people/"
+}
+
+mal_id! {
+    /// `ID` of a character.
+    CharacterId, url: Character "/character/"
+}
+
+mal_id! {
+    /// Number of an episode within its anime, starting from 1.
+    EpisodeNumber
+}
+
+mal_id! {
+    /// `ID` of a genre, theme or demographic.
+    ///
+    /// Well-known values are available as associated constants, any other `ID`
+    /// can be created with `GenreId(id)`.
+    GenreId, url: Anime "/anime/genre/"
+}
+
+#[allow(missing_docs)]
+impl GenreId {
+    // Genres.
+    pub const ACTION: Self = Self(1);
+    pub const ADVENTURE: Self = Self(2);
+    pub const AVANT_GARDE: Self = Self(5);
+    pub const AWARD_WINNING: Self = Self(46);
+    pub const BOYS_LOVE: Self = Self(28);
+    pub const COMEDY: Self = Self(4);
+    pub const DRAMA: Self = Self(8);
+    pub const FANTASY: Self = Self(10);
+    pub const GIRLS_LOVE: Self = Self(26);
+    pub const GOURMET: Self = Self(47);
+    pub const HORROR: Self = Self(14);
+    pub const MYSTERY: Self = Self(7);
+    pub const ROMANCE: Self = Self(22);
+    pub const SCI_FI: Self = Self(24);
+    pub const SLICE_OF_LIFE: Self = Self(36);
+    pub const SPORTS: Self = Self(30);
+    pub const SUPERNATURAL: Self = Self(37);
+    pub const SUSPENSE: Self = Self(41);
+
+    // Explicit genres.
+    pub const ECCHI: Self = Self(9);
+    pub const EROTICA: Self = Self(49);
+    pub const HENTAI: Self = Self(12);
+
+    // Themes.
+    pub const ADULT_CAST: Self = Self(50);
+    pub const ANTHROPOMORPHIC: Self = Self(51);
+    pub const CGDCT: Self = Self(52);
+    pub const CHILDCARE: Self = Self(53);
+    pub const COMBAT_SPORTS: Self = Self(54);
+    pub const CROSSDRESSING: Self = Self(81);
+    pub const DELINQUENTS: Self = Self(55);
+    pub const DETECTIVE: Self = Self(39);
+    pub const EDUCATIONAL: Self = Self(56);
+    pub const GAG_HUMOR: Self = Self(57);
+    pub const GORE: Self = Self(58);
+    pub const HAREM: Self = Self(35);
+    pub const HIGH_STAKES_GAME: Self = Self(59);
+    pub const HISTORICAL: Self = Self(13);
+    pub const IDOLS_FEMALE: Self = Self(60);
+    pub const IDOLS_MALE: Self = Self(61);
+    pub const ISEKAI: Self = Self(62);
+    pub const IYASHIKEI: Self = Self(63);
+    pub const LOVE_POLYGON: Self = Self(64);
+    pub const LOVE_STATUS_QUO: Self = Self(74);
+    pub const MAGICAL_SEX_SHIFT: Self = Self(65);
+    pub const MAHOU_SHOUJO: Self = Self(66);
+    pub const MARTIAL_ARTS: Self = Self(17);
+    pub const MECHA: Self = Self(18);
+    pub const MEDICAL: Self = Self(67);
+    pub const MILITARY: Self = Self(38);
+    pub const MUSIC: Self = Self(19);
+    pub const MYTHOLOGY: Self = Self(6);
+    pub const ORGANIZED_CRIME: Self = Self(68);
+    pub const OTAKU_CULTURE: Self = Self(69);
+    pub const PARODY: Self = Self(20);
+    pub const PERFORMING_ARTS: Self = Self(70);
+    pub const PETS: Self = Self(71);
+    pub const PSYCHOLOGICAL: Self = Self(40);
+    pub const RACING: Self = Self(3);
+    pub const REINCARNATION: Self = Self(72);
+    pub const REVERSE_HAREM: Self = Self(73);
+    pub const SAMURAI: Self = Self(21);
+    pub const SCHOOL: Self = Self(23);
+    pub const SHOWBIZ: Self = Self(75);
+    pub const SPACE: Self = Self(29);
+    pub const STRATEGY_GAME: Self = Self(11);
+    pub const SUPER_POWER: Self = Self(31);
+    pub const SURVIVAL: Self = Self(76);
+    pub const TEAM_SPORTS: Self = Self(77);
+    pub const TIME_TRAVEL: Self = Self(78);
+    pub const URBAN_FANTASY: Self = Self(82);
+    pub const VAMPIRE: Self = Self(32);
+    pub const VIDEO_GAME: Self = Self(79);
+    pub const VILLAINESS: Self = Self(83);
+    pub const VISUAL_ARTS: Self = Self(80);
+    pub const WORKPLACE: Self = Self(48);
+
+    // Demographics.
+    pub const JOSEI: Self = Self(43);
+    pub const KIDS: Self = Self(15);
+    pub const SEINEN: Self = Self(42);
+    pub const SHOUJO: Self = Self(25);
+    pub const SHOUNEN: Self = Self(27);
+}
+
+mal_id! {
+    /// `ID` of a producer, studio or licensor.
+    ProducerId, url: Anime "/anime/producer/"
+}
+
+/// `ID` of a recommendation, made of the two recommended entries.
+///
+/// Jikan represents it as a dashed pair like `"1-5114"`, with the lower `ID`
+/// first.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(try_from = "String", into = "String")]
+pub struct RecommendationPairId {
+    /// `ID` of the first anime.
+    pub first: AnimeId,
+    /// `ID` of the second anime.
+    pub second: AnimeId,
+}
+
+impl RecommendationPairId {
+    /// Create a pair from two anime `ID`s.
+    pub fn new(first: impl Into<AnimeId>, second: impl Into<AnimeId>) -> Self {
+        Self {
+            first: first.into(),
+            second: second.into(),
+        }
+    }
+
+    /// Returns the anime paired with `id`, if `id` is part of this pair.
+    pub fn other(&self, id: AnimeId) -> Option<AnimeId> {
+        if id == self.first {
+            Some(self.second)
+        } else if id == self.second {
+            Some(self.first)
+        } else {
+            None
+        }
+    }
+}
+
+/// Errors raised while parsing a [`RecommendationPairId`] or taking an `ID`
+/// from a [`MalUrl`].
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseIdError {
+    /// The value is not two numbers separated by a dash.
+    #[error("invalid recommendation id `{0}`, expected `<id>-<id>`")]
+    Format(String),
+    /// The URL points to another kind of entry than the `ID` type.
+    #[error("`{url}` is not a URL of the form `{expected}{{id}}`")]
+    UrlMismatch {
+        /// Path expected before the `ID`.
+        expected: &'static str,
+        /// URL of the entry.
+        url: String,
+    },
+}
+
+/// Returns the `ID` of `url` if it is of type `url_type` and its path is
+/// `prefix` followed by the `ID`.
+fn check_url(url: &MalUrl, url_type: EntryType, prefix: &'static str) -> Result<u32, ParseIdError> {
+    let matches = url.url_type == url_type
+        && Url::parse(&url.url).is_ok_and(|parsed| {
+            parsed
+                .path()
+                .strip_prefix(prefix)
+                .and_then(|rest| rest.split('/').next())
+                .is_some_and(|id| id == url.mal_id.to_string())
+        });
+    if matches {
+        Ok(url.mal_id)
+    } else {
+        Err(ParseIdError::UrlMismatch {
+            expected: prefix,
+            url: url.url.clone(),
+        })
+    }
+}
+
+impl FromStr for RecommendationPairId {
+    type Err = ParseIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split_once('-')
+            .and_then(|(first, second)| {
+                Some(Self::new(
+                    first.parse::<u32>().ok()?,
+                    second.parse::<u32>().ok()?,
+                ))
+            })
+            .ok_or_else(|| ParseIdError::Format(s.to_owned()))
+    }
+}
+
+impl TryFrom<String> for RecommendationPairId {
+    type Error = ParseIdError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<RecommendationPairId> for String {
+    fn from(id: RecommendationPairId) -> Self {
+        id.to_string()
+    }
+}
+
+impl fmt::Display for RecommendationPairId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.first, self.second)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(url_type: EntryType, mal_id: u32, url: &str) -> MalUrl {
+        MalUrl {
+            mal_id,
+            url_type,
+            name: String::new(),
+            url: url.to_owned(),
+        }
+    }
+
+    #[test]
+    fn genre_and_producer_from_url() {
+        let action = url(
+            EntryType::Anime,
+            1,
+            "https://myanimelist.net/anime/genre/1/Action",
+        );
+        assert_eq!(GenreId::try_from(&action), Ok(GenreId::ACTION));
+        assert!(ProducerId::try_from(&action).is_err());
+
+        let sunrise = url(
+            EntryType::Anime,
+            14,
+            "https://myanimelist.net/anime/producer/14/Sunrise",
+        );
+        assert_eq!(ProducerId::try_from(&sunrise), Ok(ProducerId(14)));
+        assert_eq!(
+            GenreId::try_from(&sunrise),
+            Err(ParseIdError::UrlMismatch {
+                expected: "/anime/genre/",
+                url: sunrise.url.clone(),
+            })
+        );
+
+        for invalid in [
+            url(
+                EntryType::Manga,
+                1,
+                "https://myanimelist.net/manga/genre/1/Action",
+            ),
+            url(
+                EntryType::Anime,
+                2,
+                "https://myanimelist.net/anime/genre/1/Action",
+            ),
+            url(
+                EntryType::Anime,
+                1,
+                "https://myanimelist.net/anime/1/Cowboy_Bebop",
+            ),
+            url(EntryType::Anime, 1, "not a url"),
+        ] {
+            assert!(GenreId::try_from(&invalid).is_err(), "{invalid:?}");
+        }
+    }
+
+    #[test]
+    fn entry_ids_from_url() {
+        let bebop = url(
+            EntryType::Anime,
+            1,
+            "https://myanimelist.net/anime/1/Cowboy_Bebop",
+        );
+        assert_eq!(AnimeId::try_from(&bebop), Ok(AnimeId(1)));
+        assert!(MangaId::try_from(&bebop).is_err());
+        assert!(GenreId::try_from(&bebop).is_err());
+
+        let manga = url(
+            EntryType::Manga,
+            173,
+            "https://myanimelist.net/manga/173/Cowboy_Bebop",
+        );
+        assert_eq!(MangaId::try_from(&manga), Ok(MangaId(173)));
+        assert_eq!(
+            AnimeId::try_from(&manga),
+            Err(ParseIdError::UrlMismatch {
+                expected: "/anime/",
+                url: manga.url.clone(),
+            })
+        );
+
+        let person = url(
+            EntryType::People,
+            11,
+            "https://myanimelist.net/people/11/Kouichi_Yamadera",
+        );
+        assert_eq!(PersonId::try_from(&person), Ok(PersonId(11)));
+        assert!(CharacterId::try_from(&person).is_err());
+
+        let character = url(
+            EntryType::Character,
+            1,
+            "https://myanimelist.net/character/1/Spike_Spiegel",
+        );
+        assert_eq!(CharacterId::try_from(&character), Ok(CharacterId(1)));
+        assert!(PersonId::try_from(&character).is_err());
+    }
+
+    #[test]
+    fn recommendation_pair_id() {
+        let id: RecommendationPairId = serde_json::from_str(r#""1-5114""#).unwrap();
+        assert_eq!(id, RecommendationPairId::new(1u32, 5114u32));
+        assert_eq!(id.other(AnimeId(5114)), Some(AnimeId(1)));
+        assert_eq!(id.other(AnimeId(1)), Some(AnimeId(5114)));
+        assert_eq!(id.other(AnimeId(30)), None);
+        assert_eq!(serde_json::to_string(&id).unwrap(), r#""1-5114""#);
+    }
+
+    #[test]
+    fn recommendation_pair_id_parse_errors() {
+        for invalid in [
+            "",
+            "1",
+            "1-",
+            "-5114",
+            "1-5114-3",
+            "a-b",
+            "1_5114",
+            " 1-5114",
+            "1-4294967296",
+        ] {
+            assert_eq!(
+                invalid.parse::<RecommendationPairId>(),
+                Err(ParseIdError::Format(invalid.to_owned()))
+            );
+        }
+
+        let err = serde_json::from_str::<RecommendationPairId>(r#""1+5114""#).unwrap_err();
+        assert!(err.to_string().contains("expected `<id>-<id>`"), "{err}");
+        assert!(serde_json::from_str::<RecommendationPairId>("15114").is_err());
+    }
+}
@@ -0,0 +1,350 @@
+use std::fmt;
+
+use url::Url;
+
+use super::anime::{
+    AnimeImages,
+    CharacterImages,
+    CommonImages,
+    ImageUrl,
+    ImageUrls,
+    PeopleImages,
+    Trailer,
+    TrailerBase,
+    TrailerImages,
+    UserImages,
+};
+
+/// File format of an image variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImageFormat {
+    /// JPEG image.
+    Jpg,
+    /// WebP image.
+    Webp,
+}
+
+impl ImageFormat {
+    /// Common file extension of the format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Jpg => "jpg",
+            Self::Webp => "webp",
+        }
+    }
+
+    /// MIME type of the format.
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            Self::Jpg => "image/jpeg",
+            Self::Webp => "image/webp",
+        }
+    }
+}
+
+impl fmt::Display for ImageFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.extension())
+    }
+}
+
+/// Size of an image variant, ordered from smallest to largest.
+///
+/// Sizes follow the names of the fields sent by Jikan, `image_url` being
+/// [`ImageSize::Regular`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ImageSize {
+    /// `small_image_url`.
+    Small,
+    /// `medium_image_url`.
+    Medium,
+    /// `image_url`.
+    Regular,
+    /// `large_image_url`.
+    Large,
+    /// `maximum_image_url`.
+    Maximum,
+}
+
+/// A single variant of an image.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Image {
+    /// Format of the variant.
+    pub format: ImageFormat,
+    /// Size of the variant.
+    pub size: ImageSize,
+    /// Location of the variant.
+    pub url: Url,
+}
+
+/// Every available variant of an image, whatever response it comes from.
+///
+/// Built from any of the image types in [`crate::types::anime`]. Missing URLs
+/// are skipped.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Images {
+    variants: Vec<Image>,
+}
+
+impl Images {
+    /// All available variants.
+    pub fn variants(&self) -> &[Image] {
+        &self.variants
+    }
+
+    /// Returns `true` if no variant is available.
+    pub fn is_empty(&self) -> bool {
+        self.variants.is_empty()
+    }
+
+    /// Returns the variant with exactly this format and size.
+    pub fn get(&self, format: ImageFormat, size: ImageSize) -> Option<&Image> {
+        self.variants
+            .iter()
+            .find(|image| image.format == format && image.size == size)
+    }
+
+    /// Returns the largest variant no larger than `max_size`, preferring
+    /// `format`.
+    ///
+    /// Falls back to other formats when `format` has no such variant, and to
+    /// the smallest variant when every variant is larger than `max_size`.
+    pub fn best(&self, format: ImageFormat, max_size: ImageSize) -> Option<&Image> {
+        let fitting = |format: Option<ImageFormat>| {
+            self.variants
+                .iter()
+                .filter(|image| image.size <= max_size)
+                .filter(|image| format.is_none_or(|format| image.format == format))
+                .max_by_key(|image| image.size)
+        };
+        fitting(Some(format)).or_else(|| fitting(None)).or_else(|| {
+            self.variants
+                .iter()
+                .min_by_key(|image| (image.size, image.format != format))
+        })
+    }
+
+    /// Returns the largest variant, preferring `format`.
+    pub fn largest(&self, format: ImageFormat) -> Option<&Image> {
+        self.best(format, ImageSize::Maximum)
+    }
+
+    fn push(&mut self, format: ImageFormat, size: ImageSize, url: Option<&Url>) {
+        if let Some(url) = url {
+            self.variants.push(Image {
+                format,
+                size,
+                url: url.clone(),
+            });
+        }
+    }
+
+    fn push_url(&mut self, format: ImageFormat, image: &ImageUrl) {
+        self.push(format, ImageSize::Regular, image.image_url.as_ref());
+    }
+
+    fn push_urls(&mut self, format: ImageFormat, image: &ImageUrls) {
+        self.push(format, ImageSize::Small, image.small_image_url.as_ref());
+        self.push(format, ImageSize::Regular, image.image_url.as_ref());
+        self.push(format, ImageSize::Large, image.large_image_url.as_ref());
+    }
+}
+
+impl From<&AnimeImages> for Images {
+    fn from(images: &AnimeImages) -> Self {
+        let mut result = Self::default();
+        result.push_urls(ImageFormat::Jpg, &images.jpg);
+        result.push_urls(ImageFormat::Webp, &images.webp);
+        result
+    }
+}
+
+impl From<&CharacterImages> for Images {
+    fn from(images: &CharacterImages) -> Self {
+        let mut result = Self::default();
+        result.push_url(ImageFormat::Jpg, &images.jpg);
+        result.push_urls(ImageFormat::Webp, &images.webp);
+        result
+    }
+}
+
+impl From<&UserImages> for Images {
+    fn from(images: &UserImages) -> Self {
+        let mut result = Self::default();
+        result.push_url(ImageFormat::Jpg, &images.jpg);
+        result.push_url(ImageFormat::Webp, &images.webp);
+        result
+    }
+}
+
+impl From<&PeopleImages> for Images {
+    fn from(images: &PeopleImages) -> Self {
+        let mut result = Self::default();
+        result.push_url(ImageFormat::Jpg, &images.jpg);
+        result
+    }
+}
+
+impl From<&CommonImages> for Images {
+    fn from(images: &CommonImages) -> Self {
+        let mut result = Self::default();
+        result.push_url(ImageFormat::Jpg, &images.jpg);
+        result
+    }
+}
+
+/// Trailer thumbnails are JPEG images hosted by YouTube.
+impl From<&TrailerImages> for Images {
+    fn from(images: &TrailerImages) -> Self {
+        let mut result = Self::default();
+        result.push(
+            ImageFormat::Jpg,
+            ImageSize::Small,
+            images.small_image_url.as_ref(),
+        );
+        result.push(
+            ImageFormat::Jpg,
+            ImageSize::Medium,
+            images.medium_image_url.as_ref(),
+        );
+        result.push(
+            ImageFormat::Jpg,
+            ImageSize::Regular,
+            images.image_url.as_ref(),
+        );
+        result.push(
+            ImageFormat::Jpg,
+            ImageSize::Large,
+            images.large_image_url.as_ref(),
+        );
+        result.push(
+            ImageFormat::Jpg,
+            ImageSize::Maximum,
+            images.maximum_image_url.as_ref(),
+        );
+        result
+    }
+}
+
+impl From<&TrailerBase> for Images {
+    fn from(trailer: &TrailerBase) -> Self {
+        trailer.images.as_ref().map(Self::from).unwrap_or_default()
+    }
+}
+
+impl From<&Trailer> for Images {
+    fn from(trailer: &Trailer) -> Self {
+        Self::from(&trailer.images)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(url: &str) -> Option<Url> {
+        Some(Url::parse(url).unwrap())
+    }
+
+    fn urls(base: &str, ext: &str) -> ImageUrls {
+        ImageUrls {
+            image_url: url(&format!("{base}.{ext}")),
+            small_image_url: url(&format!("{base}t.{ext}")),
+            large_image_url: url(&format!("{base}l.{ext}")),
+        }
+    }
+
+    fn best(images: &Images, format: ImageFormat, max_size: ImageSize) -> &str {
+        images.best(format, max_size).unwrap().url.as_str()
+    }
+
+    #[test]
+    fn best_variant() {
+        let base = "https://cdn.myanimelist.net/images/anime/4/19644";
+        let images = Images::from(&AnimeImages {
+            jpg: urls(base, "jpg"),
+            webp: urls(base, "webp"),
+        });
+        assert_eq!(images.variants().len(), 6);
+
+        assert_eq!(
+            best(&images, ImageFormat::Webp, ImageSize::Maximum),
+            format!("{base}l.webp")
+        );
+        assert_eq!(
+            best(&images, ImageFormat::Jpg, ImageSize::Regular),
+            format!("{base}.jpg")
+        );
+        // No medium variant, the next smaller one is used.
+        assert_eq!(
+            best(&images, ImageFormat::Jpg, ImageSize::Medium),
+            format!("{base}t.jpg")
+        );
+    }
+
+    #[test]
+    fn best_variant_fallbacks() {
+        let images = Images::from(&CommonImages {
+            jpg: ImageUrl {
+                image_url: url("https://cdn.myanimelist.net/images/news/1.jpg"),
+            },
+        });
+        // Other format.
+        assert_eq!(
+            images
+                .best(ImageFormat::Webp, ImageSize::Large)
+                .unwrap()
+                .format,
+            ImageFormat::Jpg
+        );
+        // Everything is larger than requested.
+        assert_eq!(
+            images
+                .best(ImageFormat::Jpg, ImageSize::Small)
+                .unwrap()
+                .size,
+            ImageSize::Regular
+        );
+
+        let images = Images::from(&CharacterImages {
+            jpg: ImageUrl { image_url: None },
+            webp: ImageUrls {
+                image_url: None,
+                small_image_url: None,
+                large_image_url: None,
+            },
+        });
+        assert!(images.is_empty());
+        assert_eq!(images.best(ImageFormat::Jpg, ImageSize::Maximum), None);
+    }
+
+    #[test]
+    fn trailer_thumbnails() {
+        let mut trailer = TrailerBase {
+            youtube_id: None,
+            url: None,
+            embed_url: None,
+            images: None,
+        };
+        assert!(Images::from(&trailer).is_empty());
+
+        trailer.images = Some(TrailerImages {
+            image_url: url("https://img.youtube.com/vi/gY5nDXOtv_o/default.jpg"),
+            small_image_url: None,
+            medium_image_url: None,
+            large_image_url: None,
+            maximum_image_url: url("https://img.youtube.com/vi/gY5nDXOtv_o/maxresdefault.jpg"),
+        });
+        let images = Images::from(&trailer);
+        assert_eq!(
+            images.largest(ImageFormat::Webp).unwrap().size,
+            ImageSize::Maximum
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_urls() {
+        let image = serde_json::from_str::<ImageUrl>(r#"{"image_url": "not a url"}"#);
+        assert!(image.is_err());
+    }
+}
@@ -0,0 +1,254 @@
+use std::fmt;
+
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use super::EpisodeNumber;
+
+/// Opening or ending song of an anime.
+///
+/// Parsed from the strings sent by Jikan, e.g.
+/// `1: "Kaikai Kitan (廻廻奇譚)" by Eve (eps 1-13)`. Parsing never fails, parts
+/// that could not be recognised are left empty and the original string is
+/// always available as [`ThemeSong::as_str`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
+pub struct ThemeSong {
+    index: Option<u32>,
+    title: String,
+    title_native: Option<String>,
+    artist: Option<String>,
+    episode_ranges: Vec<EpisodeRange>,
+    raw: String,
+}
+
+/// Inclusive range of episodes a theme song is used in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EpisodeRange {
+    /// First episode of the range.
+    pub start: EpisodeNumber,
+    /// Last episode of the range, `None` if the range is open-ended.
+    pub end: Option<EpisodeNumber>,
+}
+
+impl EpisodeRange {
+    /// Returns `true` if `episode` is part of the range.
+    pub fn contains(&self, episode: impl Into<EpisodeNumber>) -> bool {
+        let episode = episode.into();
+        self.start <= episode && self.end.is_none_or(|end| episode <= end)
+    }
+
+    fn parse(range: &str) -> Option<Self> {
+        let range = range.trim();
+        let (start, end) = match range.split_once('-') {
+            Some((start, "")) => (start, None),
+            Some((start, end)) => (start, Some(end)),
+            None => (range, Some(range)),
+        };
+        let parse = |ep: &str| ep.trim().parse().map(EpisodeNumber).ok();
+        Some(Self {
+            start: parse(start)?,
+            end: match end {
+                Some(end) => Some(parse(end)?),
+                None => None,
+            },
+        })
+    }
+}
+
+impl fmt::Display for EpisodeRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.end {
+            Some(end) if end == self.start => write!(f, "{}", self.start),
+            Some(end) => write!(f, "{}-{}", self.start, end),
+            None => write!(f, "{}-", self.start),
+        }
+    }
+}
+
+impl ThemeSong {
+    /// Parses a theme string as sent by Jikan.
+    pub fn parse(raw: impl Into<String>) -> Self {
+        let raw = raw.into();
+        let mut song = Self {
+            index: None,
+            title: String::new(),
+            title_native: None,
+            artist: None,
+            episode_ranges: Vec::new(),
+            raw: String::new(),
+        };
+
+        let mut rest = raw.trim();
+        if let Some((index, tail)) = rest.split_once(':')
+            && let Ok(index) = index.trim_start_matches('#').parse()
+        {
+            song.index = Some(index);
+            rest = tail.trim_start();
+        }
+
+        if let Some(head) = rest.strip_suffix(')')
+            && let Some((head, episodes)) = head.rsplit_once('(')
+            && let Some(episodes) = episodes
+                .strip_prefix("eps")
+                .or_else(|| episodes.strip_prefix("ep"))
+        {
+            song.episode_ranges = episodes
+                .split(',')
+                .filter_map(EpisodeRange::parse)
+                .collect();
+            rest = head.trim_end();
+        }
+
+        let (title, artist) = match rest.strip_prefix('"').and_then(|r| r.rsplit_once('"')) {
+            Some((title, artist)) => (title, artist.trim().strip_prefix("by ").unwrap_or(artist)),
+            None => match rest.split_once(" by ") {
+                Some((title, artist)) => (title, artist),
+                None => (rest, ""),
+            },
+        };
+        let artist = artist.trim();
+        song.artist = (!artist.is_empty()).then(|| artist.to_owned());
+
+        let title = title.trim();
+        match title.strip_suffix(')').and_then(|t| t.rsplit_once(" (")) {
+            Some((romanised, native)) if !native.is_ascii() => {
+                song.title = romanised.trim().to_owned();
+                song.title_native = Some(native.to_owned());
+            }
+            _ => song.title = title.to_owned(),
+        }
+
+        song.raw = raw;
+        song
+    }
+
+    /// Returns the string as sent by Jikan.
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    /// Position of the song in the list of openings or endings, if numbered.
+    pub fn index(&self) -> Option<u32> {
+        self.index
+    }
+
+    /// Title of the song, romanised when a native title is given.
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Title in the original script, if given.
+    pub fn title_native(&self) -> Option<&str> {
+        self.title_native.as_deref()
+    }
+
+    /// Performing artist(s), if given.
+    pub fn artist(&self) -> Option<&str> {
+        self.artist.as_deref()
+    }
+
+    /// Episodes the song is used in. Empty when not specified.
+    pub fn episode_ranges(&self) -> &[EpisodeRange] {
+        &self.episode_ranges
+    }
+
+    /// Returns `true` if the song is used in `episode`.
+    ///
+    /// Songs without episode information are never matched.
+    pub fn plays_in(&self, episode: impl Into<EpisodeNumber>) -> bool {
+        let episode = episode.into();
+        self.episode_ranges
+            .iter()
+            .any(|range| range.contains(episode))
+    }
+}
+
+impl From<String> for ThemeSong {
+    fn from(raw: String) -> Self {
+        Self::parse(raw)
+    }
+}
+
+impl From<&str> for ThemeSong {
+    fn from(raw: &str) -> Self {
+        Self::parse(raw)
+    }
+}
+
+impl From<ThemeSong> for String {
+    fn from(song: ThemeSong) -> Self {
+        song.raw
+    }
+}
+
+impl fmt::Display for ThemeSong {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(start: u32, end: Option<u32>) -> EpisodeRange {
+        EpisodeRange {
+            start: EpisodeNumber(start),
+            end: end.map(EpisodeNumber),
+        }
+    }
+
+    #[test]
+    fn parse() {
+        let song = ThemeSong::parse(r#"1: "Tank!" by The Seatbelts (eps 1-26)"#);
+        assert_eq!(song.index(), Some(1));
+        assert_eq!(song.title(), "Tank!");
+        assert_eq!(song.title_native(), None);
+        assert_eq!(song.artist(), Some("The Seatbelts"));
+        assert_eq!(song.episode_ranges(), [range(1, Some(26))]);
+
+        let song = ThemeSong::parse(r#""Kaikai Kitan (廻廻奇譚)" by Eve (eps 1-13)"#);
+        assert_eq!(song.index(), None);
+        assert_eq!(song.title(), "Kaikai Kitan");
+        assert_eq!(song.title_native(), Some("廻廻奇譚"));
+        assert_eq!(song.artist(), Some("Eve"));
+
+        let song = ThemeSong::parse(
+            r#"1: "The Real Folk Blues" by The Seatbelts feat. Mai Yamane (eps 1-12, 14-25)"#,
+        );
+        assert_eq!(song.artist(), Some("The Seatbelts feat. Mai Yamane"));
+        assert_eq!(song.episode_ranges(), [
+            range(1, Some(12)),
+            range(14, Some(25))
+        ]);
+        assert!(song.plays_in(12u32));
+        assert!(!song.plays_in(13u32));
+
+        let song = ThemeSong::parse(r#"#3: "Blue" by The Seatbelts feat. Mai Yamane (ep 26)"#);
+        assert_eq!(song.index(), Some(3));
+        assert_eq!(song.episode_ranges(), [range(26, Some(26))]);
+
+        let song =
+            ThemeSong::parse(r#"2: "Hacking to the Gate" by Kanako Itou (Mayuri Shiina) (eps 2-)"#);
+        assert_eq!(song.title(), "Hacking to the Gate");
+        assert_eq!(song.artist(), Some("Kanako Itou (Mayuri Shiina)"));
+        assert_eq!(song.episode_ranges(), [range(2, None)]);
+        assert!(song.plays_in(100u32));
+
+        let song = ThemeSong::parse("No opening themes have been added to this title.");
+        assert_eq!(song.title(), song.as_str());
+        assert_eq!(song.artist(), None);
+        assert!(song.episode_ranges().is_empty());
+    }
+
+    #[test]
+    fn serde_keeps_raw_string() {
+        let raw = r#""1: \"Tank!\" by The Seatbelts (eps 1-26)""#;
+        let song: ThemeSong = serde_json::from_str(raw).unwrap();
+        assert_eq!(song.title(), "Tank!");
+        assert_eq!(serde_json::to_string(&song).unwrap(), raw);
+    }
+}
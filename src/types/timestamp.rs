@@ -0,0 +1,77 @@
+use std::fmt;
+
+use iso8601_timestamp::Timestamp;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+/// Date and time as sent by Jikan, e.g. `"2008-08-09T06:20:00+00:00"`.
+///
+/// The raw string is kept as-is, the parsed [`Timestamp`] is `None` when the
+/// value is not a valid ISO 8601 timestamp.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
+pub struct ApiTimestamp {
+    raw: String,
+    timestamp: Option<Timestamp>,
+}
+
+impl ApiTimestamp {
+    /// Returns the string as sent by Jikan.
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    /// Returns the parsed timestamp, if valid.
+    pub fn timestamp(&self) -> Option<Timestamp> {
+        self.timestamp
+    }
+}
+
+impl From<String> for ApiTimestamp {
+    fn from(raw: String) -> Self {
+        Self {
+            timestamp: Timestamp::parse(&raw),
+            raw,
+        }
+    }
+}
+
+impl From<&str> for ApiTimestamp {
+    fn from(raw: &str) -> Self {
+        raw.to_owned().into()
+    }
+}
+
+impl From<ApiTimestamp> for String {
+    fn from(value: ApiTimestamp) -> Self {
+        value.raw
+    }
+}
+
+impl fmt::Display for ApiTimestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_raw_string() {
+        let date = ApiTimestamp::from("2008-08-09T06:20:00+00:00");
+        assert_eq!(date.as_str(), "2008-08-09T06:20:00+00:00");
+        assert_eq!(date.timestamp(), Timestamp::parse("2008-08-09T06:20:00Z"));
+        assert_eq!(
+            serde_json::to_string(&date).unwrap(),
+            r#""2008-08-09T06:20:00+00:00""#
+        );
+
+        let date: ApiTimestamp = serde_json::from_str(r#""Aug 9, 2008""#).unwrap();
+        assert_eq!(date.as_str(), "Aug 9, 2008");
+        assert_eq!(date.timestamp(), None);
+    }
+}
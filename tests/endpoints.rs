@@ -0,0 +1,313 @@
+//! Queries every `/anime` endpoint against the fixtures, in strict mode so
+//! that any field not modeled by the types fails the test.
+
+use jikan_rs::{
+    api::{
+        ApiError,
+        AsyncQuery,
+        DecodeMode,
+        PagedEndpointExt,
+        Query,
+        ResponseError,
+        UnknownField,
+        anime,
+        mock::{
+            MockClient,
+            MockRequest,
+        },
+    },
+    types::{
+        AnimeId,
+        RecommendationPairId,
+        anime::{
+            Anime,
+            AnimeCharacter,
+            AnimeEpisode,
+            AnimeExternal,
+            AnimeForumPosts,
+            AnimeFull,
+            AnimeMoreInfo,
+            AnimeNewsMeta,
+            AnimePictures,
+            AnimeRecommendations,
+            AnimeRelations,
+            AnimeReview,
+            AnimeStaff,
+            AnimeStatistics,
+            AnimeStreaming,
+            AnimeUserUpdate,
+            AnimeVideos,
+            EpisodeVideo,
+            Theme,
+        },
+    },
+};
+
+macro_rules! fixture {
+    ($path:literal) => {
+        include_str!(concat!("fixtures/anime/", $path))
+    };
+}
+
+fn client() -> MockClient {
+    let paged = |path: &str| MockRequest::get(path).query("page", "1");
+    MockClient::new()
+        .with_decode_mode(DecodeMode::Strict)
+        .with_decode_warning_handler(|warning| panic!("{warning}"))
+        .respond_json(MockRequest::get("/anime/1"), fixture!("anime.json"))
+        .respond_json(
+            MockRequest::get("/anime/1/full"),
+            fixture!("anime_full.json"),
+        )
+        .respond_json(
+            MockRequest::get("/anime/1/characters"),
+            fixture!("characters.json"),
+        )
+        .respond_json(
+            MockRequest::get("/anime/1/episodes/5"),
+            fixture!("episode.json"),
+        )
+        .respond_json(paged("/anime/1/episodes"), fixture!("episodes.json"))
+        .respond_json(
+            MockRequest::get("/anime/1/external"),
+            fixture!("external.json"),
+        )
+        .respond_json(
+            MockRequest::get("/anime/1/forum").query("filter", "episode"),
+            fixture!("forum.json"),
+        )
+        .respond_json(
+            MockRequest::get("/anime/1/moreinfo"),
+            fixture!("moreinfo.json"),
+        )
+        .respond_json(paged("/anime/1/news"), fixture!("news.json"))
+        .respond_json(
+            MockRequest::get("/anime/1/pictures"),
+            fixture!("pictures.json"),
+        )
+        .respond_json(
+            MockRequest::get("/anime/1/recommendations"),
+            fixture!("recommendations.json"),
+        )
+        .respond_json(
+            MockRequest::get("/anime/1/relations"),
+            fixture!("relations.json"),
+        )
+        .respond_json(
+            paged("/anime/1/reviews").query("spoilers", "true"),
+            fixture!("reviews.json"),
+        )
+        .respond_json(
+            paged("/anime").query("q", "bebop").query("limit", "1"),
+            fixture!("search.json"),
+        )
+        .respond_json(MockRequest::get("/anime/1/staff"), fixture!("staff.json"))
+        .respond_json(
+            MockRequest::get("/anime/1/statistics"),
+            fixture!("statistics.json"),
+        )
+        .respond_json(
+            MockRequest::get("/anime/1/streaming"),
+            fixture!("streaming.json"),
+        )
+        .respond_json(MockRequest::get("/anime/1/themes"), fixture!("themes.json"))
+        .respond_json(paged("/anime/1/userupdates"), fixture!("userupdates.json"))
+        .respond_json(MockRequest::get("/anime/1/videos"), fixture!("videos.json"))
+        .respond_json(
+            paged("/anime/1/videos/episodes"),
+            fixture!("videos_episodes.json"),
+        )
+}
+
+#[test]
+fn anime() {
+    let client = client();
+    let endpoint = anime::Anime::builder().id(1u32).build().unwrap();
+    let anime: Anime = endpoint.query(&client).unwrap();
+    assert_eq!(anime.title, "Cowboy Bebop");
+
+    let endpoint = anime::AnimeFull::builder().id(1u32).build().unwrap();
+    let full: AnimeFull = endpoint.query(&client).unwrap();
+    assert_eq!(Anime::from(full), anime);
+}
+
+#[test]
+fn unknown_fields_name_their_type() {
+    let mut value: serde_json::Value = serde_json::from_str(fixture!("anime.json")).unwrap();
+    value["data"]["new_field"] = 1.into();
+    value["data"]["trailer"]["images"]["new_field"] = 1.into();
+    let client = MockClient::new()
+        .with_decode_mode(DecodeMode::Strict)
+        .respond_json(MockRequest::get("/anime/1"), value.to_string());
+
+    let endpoint = anime::Anime::builder().id(1u32).build().unwrap();
+    let result: Result<Anime, _> = endpoint.query(&client);
+    let err = result.unwrap_err();
+    let ApiError::Response {
+        source: ResponseError::UnknownFields { fields, .. },
+        ..
+    } = err
+    else {
+        panic!("{err}");
+    };
+    let fields: Vec<_> = fields
+        .iter()
+        .map(|UnknownField { path, type_name }| (path.as_str(), *type_name))
+        .collect();
+    assert_eq!(fields, [
+        ("data.new_field", "Anime"),
+        ("data.trailer.images.new_field", "TrailerImages"),
+    ]);
+}
+
+#[test]
+fn characters_and_staff() {
+    let client = client();
+    let endpoint = anime::Characters::builder().id(1u32).build().unwrap();
+    let characters: Vec<AnimeCharacter> = endpoint.query(&client).unwrap();
+    assert_eq!(characters[0].favorites, Some(50193));
+
+    let endpoint = anime::Staff::builder().id(1u32).build().unwrap();
+    let staff: AnimeStaff = endpoint.query(&client).unwrap();
+    assert_eq!(staff[0].positions, ["Director", "Script", "Storyboard"]);
+}
+
+#[test]
+fn episodes() {
+    let client = client();
+    let endpoint = anime::Episodes::builder().id(1u32).build().unwrap();
+    let page = endpoint.first_page::<AnimeEpisode, _>(&client).unwrap();
+    assert_eq!(page.items.len(), 4);
+    assert!(page.items[0].synopsis.is_none());
+
+    let endpoint = anime::Episode::builder()
+        .id(1u32)
+        .episode(5u32)
+        .build()
+        .unwrap();
+    let episode: AnimeEpisode = endpoint.query(&client).unwrap();
+    assert_eq!(episode.title, "Ballad of Fallen Angels");
+    assert_eq!(episode.duration, Some(1460));
+    assert!(episode.synopsis.is_some());
+}
+
+#[test]
+fn links() {
+    let client = client();
+    let endpoint = anime::External::builder().id(1u32).build().unwrap();
+    let external: AnimeExternal = endpoint.query(&client).unwrap();
+    assert_eq!(external[0].name, "Official Site");
+
+    let endpoint = anime::Streaming::builder().id(1u32).build().unwrap();
+    let streaming: AnimeStreaming = endpoint.query(&client).unwrap();
+    assert_eq!(streaming.len(), 2);
+}
+
+#[test]
+fn forum() {
+    let client = client();
+    let endpoint = anime::Forum::builder()
+        .id(1u32)
+        .filter(anime::ForumFilter::Episode)
+        .build()
+        .unwrap();
+    let posts: AnimeForumPosts = endpoint.query(&client).unwrap();
+    assert_eq!(posts[0].comments, 214);
+    assert_eq!(posts[0].date.as_str(), "2022-03-18T00:00:00+00:00");
+    assert!(posts[0].date.timestamp().is_some());
+    assert!(posts[1].last_comment.date.is_none());
+}
+
+#[test]
+fn more_info_and_statistics() {
+    let client = client();
+    let endpoint = anime::MoreInfo::builder().id(1u32).build().unwrap();
+    let info: AnimeMoreInfo = endpoint.query(&client).unwrap();
+    assert!(info.moreinfo.unwrap().starts_with("Suggested Order"));
+
+    let endpoint = anime::Statistics::builder().id(1u32).build().unwrap();
+    let statistics: AnimeStatistics = endpoint.query(&client).unwrap();
+    assert_eq!(statistics.scores.len(), 10);
+    let votes: u32 = statistics.scores.iter().map(|score| score.votes).sum();
+    assert_eq!(votes, 812743);
+}
+
+#[test]
+fn news_reviews_and_user_updates() {
+    let client = client();
+    let endpoint = anime::News::builder().id(1u32).build().unwrap();
+    let news = endpoint.first_page::<AnimeNewsMeta, _>(&client).unwrap();
+    assert!(!news.items.is_empty());
+
+    let endpoint = anime::Reviews::builder()
+        .id(1u32)
+        .spoilers(true)
+        .build()
+        .unwrap();
+    let reviews = endpoint.first_page::<AnimeReview, _>(&client).unwrap();
+    assert_eq!(reviews.items[0].user.username, "Crimson");
+    assert!(reviews.has_next_page());
+
+    let endpoint = anime::UserUpdates::builder().id(1u32).build().unwrap();
+    let updates = endpoint.first_page::<AnimeUserUpdate, _>(&client).unwrap();
+    assert!(!updates.items.is_empty());
+}
+
+#[test]
+fn pictures_and_videos() {
+    let client = client();
+    let endpoint = anime::Pictures::builder().id(1u32).build().unwrap();
+    let pictures: AnimePictures = endpoint.query(&client).unwrap();
+    assert_eq!(pictures.len(), 2);
+
+    let endpoint = anime::Videos::builder().id(1u32).build().unwrap();
+    let videos: AnimeVideos = endpoint.query(&client).unwrap();
+    assert_eq!(videos.promo[0].title, "PV 2");
+    let meta = videos.music_videos[0].meta.as_ref().unwrap();
+    assert_eq!(meta.title.as_deref(), Some("Tank!"));
+
+    let endpoint = anime::VideosEpisodes::builder().id(1u32).build().unwrap();
+    let episodes = endpoint.first_page::<EpisodeVideo, _>(&client).unwrap();
+    assert_eq!(episodes.items, videos.episodes);
+}
+
+#[test]
+fn recommendations_and_relations() {
+    let client = client();
+    let endpoint = anime::Recommendations::builder().id(1u32).build().unwrap();
+    let recommendations: AnimeRecommendations = endpoint.query(&client).unwrap();
+    assert_eq!(
+        recommendations[0].mal_id,
+        RecommendationPairId::new(1u32, 205u32)
+    );
+    assert_eq!(recommendations[0].entry[1].mal_id, AnimeId(205));
+
+    let endpoint = anime::Relations::builder().id(1u32).build().unwrap();
+    let relations: AnimeRelations = endpoint.query(&client).unwrap();
+    assert_eq!(relations.len(), 3);
+    assert_eq!(relations[0].entry.len(), 2);
+}
+
+#[test]
+fn search() {
+    let client = client();
+    let endpoint = anime::AnimeSearch::builder()
+        .query("bebop")
+        .limit(1u32)
+        .build()
+        .unwrap();
+    let page = endpoint.first_page::<Anime, _>(&client).unwrap();
+    assert_eq!(page.items[0].mal_id, AnimeId(1));
+    assert_eq!(page.pagination.current_page, Some(1));
+    assert_eq!(page.total(), Some(2));
+}
+
+#[tokio::test]
+async fn themes_async() {
+    let client = client();
+    let endpoint = anime::Themes::builder().id(1u32).build().unwrap();
+    let theme: Theme = endpoint.query_async(&client).await.unwrap();
+    assert_eq!(theme.openings[0].title(), "Tank!");
+    assert_eq!(theme.endings.len(), 3);
+    assert_eq!(client.requests(), [MockRequest::get("/anime/1/themes")]);
+}
@@ -0,0 +1,137 @@
+use std::time::Duration;
+
+use iso8601_timestamp::Timestamp;
+use jikan_rs::types::{
+    AnimeId,
+    Root,
+    anime::{
+        Anime,
+        AnimeCharacters,
+        AnimeEpisodes,
+        AnimeFull,
+        AnimeNews,
+        AnimeReviews,
+        AnimeUserUpdates,
+    },
+};
+use serde::{
+    Serialize,
+    de::DeserializeOwned,
+};
+use url::Url;
+
+macro_rules! fixture {
+    ($path:literal) => {
+        include_str!(concat!("fixtures/", $path))
+    };
+}
+
+fn timestamp(ts: &str) -> Option<Timestamp> {
+    Some(Timestamp::parse(ts).unwrap())
+}
+
+/// Deserializes a fixture, serializes it back and checks that nothing was lost
+/// on the way.
+fn round_trip<T>(json: &str) -> Root<T>
+where
+    T: Serialize + DeserializeOwned + PartialEq + std::fmt::Debug,
+{
+    let parsed: Root<T> = serde_json::from_str(json).expect("fixture should deserialize");
+    let serialized = serde_json::to_string(&parsed).expect("response should serialize");
+    let reparsed: Root<T> = serde_json::from_str(&serialized).expect("output should deserialize");
+    assert_eq!(parsed, reparsed);
+    parsed
+}
+
+#[test]
+fn anime_round_trip() {
+    let root = round_trip::<Anime>(fixture!("anime/anime.json"));
+    assert_eq!(root.data.mal_id, AnimeId(1));
+    assert_eq!(root.data.title, "Cowboy Bebop");
+    assert_eq!(root.data.genres.len(), 3);
+    assert!(root.pagination.is_none());
+
+    let duration = root.data.duration.unwrap();
+    assert_eq!(duration.as_str(), "24 min per ep");
+    assert_eq!(duration.duration(), Some(Duration::from_secs(24 * 60)));
+    assert!(duration.is_per_episode());
+    assert_eq!(
+        root.data.aired.from.as_deref(),
+        Some("1998-04-03T00:00:00+00:00")
+    );
+    assert_eq!(
+        root.data.aired.to.as_deref(),
+        Some("1999-04-24T00:00:00+00:00")
+    );
+    let aired = root.data.aired.dates();
+    assert_eq!(aired.days(), Some(386));
+    assert_eq!(Some(aired.to_string()), root.data.aired.string);
+    let thumbnails = root.data.trailer.images.unwrap();
+    assert_eq!(
+        thumbnails.maximum_image_url.as_ref().map(Url::as_str),
+        Some("https://img.youtube.com/vi/gY5nDXOtv_o/maxresdefault.jpg")
+    );
+}
+
+#[test]
+fn anime_full_round_trip() {
+    let root = round_trip::<AnimeFull>(fixture!("anime/anime_full.json"));
+    assert_eq!(root.data.relations.len(), 3);
+    assert_eq!(root.data.theme.endings.len(), 3);
+    let ending = &root.data.theme.endings[1];
+    assert_eq!(ending.index(), Some(2));
+    assert_eq!(ending.title(), "Space Lion");
+    assert_eq!(ending.artist(), Some("The Seatbelts"));
+    assert!(ending.plays_in(13u32));
+    assert_eq!(root.data.streaming.len(), 2);
+
+    let anime = round_trip::<Anime>(fixture!("anime/anime.json")).data;
+    assert_eq!(root.data.title, anime.title);
+    assert_eq!(Anime::from(root.data), anime);
+}
+
+#[test]
+fn characters_round_trip() {
+    let root = round_trip::<AnimeCharacters>(fixture!("anime/characters.json"));
+    assert_eq!(root.data.len(), 2);
+    assert_eq!(root.data[0].voice_actors.len(), 4);
+    assert!(root.data[1].voice_actors.is_empty());
+}
+
+#[test]
+fn episodes_round_trip() {
+    let root = round_trip::<AnimeEpisodes>(fixture!("anime/episodes.json"));
+    let aired = root.data[0].aired.as_ref().unwrap();
+    assert_eq!(aired.as_str(), "1998-10-24T00:00:00+00:00");
+    assert_eq!(aired.timestamp(), timestamp("1998-10-24T00:00:00Z"));
+    assert_eq!(root.data[3].aired, None);
+}
+
+#[test]
+fn news_round_trip() {
+    let root = round_trip::<AnimeNews>(fixture!("anime/news.json"));
+    assert_eq!(
+        root.data[0].date.timestamp(),
+        timestamp("2021-12-10T10:58:00Z")
+    );
+}
+
+#[test]
+fn reviews_round_trip() {
+    let root = round_trip::<AnimeReviews>(fixture!("anime/reviews.json"));
+    assert_eq!(
+        root.data[0].date.timestamp(),
+        timestamp("2008-08-09T06:20:00Z")
+    );
+    assert!(root.pagination.unwrap().has_next_page);
+}
+
+#[test]
+fn user_updates_round_trip() {
+    let root = round_trip::<AnimeUserUpdates>(fixture!("anime/userupdates.json"));
+    assert_eq!(
+        root.data[1].date.timestamp(),
+        timestamp("2025-01-15T08:47:10Z")
+    );
+    assert_eq!(root.data[1].score, None);
+}
@@ -0,0 +1,54 @@
+#!/usr/bin/env bash
+# Captures the fixtures from the Jikan API.
+#
+# Every fixture is the response of the request answered with it in
+# tests/endpoints.rs, trimmed to its first items where the lists are long.
+# Jikan allows 3 requests per second, the script waits between requests.
+#
+# Usage: tests/fixtures/capture.sh [fixture.json...]
+
+set -euo pipefail
+
+API="${JIKAN_API:-https://api.jikan.moe/v4}"
+DIR="$(cd "$(dirname "$0")" && pwd)/anime"
+# Number of items kept in each list.
+KEEP="${KEEP:-3}"
+
+declare -A REQUESTS=(
+    [anime.json]="/anime/1"
+    [anime_full.json]="/anime/1/full"
+    [characters.json]="/anime/1/characters"
+    [episode.json]="/anime/1/episodes/5"
+    [episodes.json]="/anime/1/episodes?page=1"
+    [external.json]="/anime/1/external"
+    [forum.json]="/anime/1/forum?filter=episode"
+    [moreinfo.json]="/anime/1/moreinfo"
+    [news.json]="/anime/1/news?page=1"
+    [pictures.json]="/anime/1/pictures"
+    [recommendations.json]="/anime/1/recommendations"
+    [relations.json]="/anime/1/relations"
+    [reviews.json]="/anime/1/reviews?page=1&spoilers=true"
+    [search.json]="/anime?q=bebop&limit=1&page=1"
+    [staff.json]="/anime/1/staff"
+    [statistics.json]="/anime/1/statistics"
+    [streaming.json]="/anime/1/streaming"
+    [themes.json]="/anime/1/themes"
+    [userupdates.json]="/anime/1/userupdates?page=1"
+    [videos.json]="/anime/1/videos"
+    [videos_episodes.json]="/anime/1/videos/episodes?page=1"
+)
+
+# Keeps the first items of list responses, single resources are left as sent.
+TRIM='.data |= if type == "array" then .[:$keep] else . end'
+
+if [ "$#" -eq 0 ]; then
+    set -- "${!REQUESTS[@]}"
+fi
+
+for fixture in "$@"; do
+    request="${REQUESTS[$fixture]:?unknown fixture $fixture}"
+    echo "$fixture <- $API$request" >&2
+    curl --fail --silent --show-error "$API$request" |
+        jq --indent 2 --argjson keep "$KEEP" "$TRIM" >"$DIR/$fixture"
+    sleep 1
+done